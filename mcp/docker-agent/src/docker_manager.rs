@@ -21,6 +21,13 @@ pub enum DockerError {
 
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
+
+    #[error("Command in container {container_id} exited with code {exit_code}: {message}")]
+    ExecNonZeroExit {
+        container_id: String,
+        exit_code: i64,
+        message: String,
+    },
 }
 
 /// Manages long-running Docker containers
@@ -31,6 +38,7 @@ pub struct DockerManager {
 }
 
 /// State of a tracked container
+#[derive(Clone)]
 pub struct ContainerState {
     pub id: String,
     pub name: String,
@@ -40,6 +48,7 @@ pub struct ContainerState {
 }
 
 /// Container lifecycle status
+#[derive(Clone)]
 pub enum ContainerStatus {
     Running,
     Stopped,
@@ -52,8 +61,240 @@ pub struct StartConfig {
     pub image: String,
     pub command: Option<Vec<String>>,
     pub env_vars: Vec<String>,
-    pub volume_mounts: Vec<(String, String)>, // (host_path, container_path)
+    /// Volume/bind mount specs, in `host_path:container_path[:ro|rw|z|Z[,...]]`
+    /// or bare-named-volume form; parsed by [`parse_volume_mount`]
+    pub volume_mounts: Vec<String>,
     pub name: Option<String>,
+    /// Published ports, in `host:container[/proto]` form
+    pub ports: Vec<String>,
+    /// Memory limit in bytes
+    pub memory_bytes: Option<u64>,
+    /// Total memory+swap limit in bytes (-1 for unlimited swap); only meaningful
+    /// alongside `memory_bytes`
+    pub memory_swap_bytes: Option<i64>,
+    /// CPU quota, in nanocpus (1 CPU = 1_000_000_000)
+    pub nano_cpus: Option<i64>,
+    /// Relative CPU weight versus other containers (default: 1024)
+    pub cpu_shares: Option<i64>,
+    /// Restart policy
+    pub restart: Option<RestartPolicy>,
+    /// Labels to attach to the container
+    pub labels: HashMap<String, String>,
+}
+
+/// Restart policy for a started container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartPolicy {
+    No,
+    OnFailure { max_retries: u32 },
+    Always,
+    UnlessStopped,
+}
+
+impl std::str::FromStr for RestartPolicy {
+    type Err = DockerError;
+
+    /// Parse a `no|on-failure:<n>|always|unless-stopped` spec, as accepted by
+    /// `docker run --restart`.
+    fn from_str(spec: &str) -> Result<Self, DockerError> {
+        if let Some(max_retries) = spec.strip_prefix("on-failure:") {
+            let max_retries: u32 = max_retries.parse().map_err(|_| {
+                DockerError::InvalidConfig(format!("invalid on-failure retry count in '{spec}'"))
+            })?;
+            return Ok(RestartPolicy::OnFailure { max_retries });
+        }
+
+        match spec {
+            "no" => Ok(RestartPolicy::No),
+            "always" => Ok(RestartPolicy::Always),
+            "unless-stopped" => Ok(RestartPolicy::UnlessStopped),
+            other => Err(DockerError::InvalidConfig(format!(
+                "unknown restart policy '{other}'"
+            ))),
+        }
+    }
+}
+
+impl From<RestartPolicy> for bollard::models::RestartPolicy {
+    fn from(policy: RestartPolicy) -> Self {
+        use bollard::models::RestartPolicyNameEnum;
+
+        let (name, maximum_retry_count) = match policy {
+            RestartPolicy::No => (RestartPolicyNameEnum::NO, None),
+            RestartPolicy::OnFailure { max_retries } => {
+                (RestartPolicyNameEnum::ON_FAILURE, Some(max_retries as i64))
+            }
+            RestartPolicy::Always => (RestartPolicyNameEnum::ALWAYS, None),
+            RestartPolicy::UnlessStopped => (RestartPolicyNameEnum::UNLESS_STOPPED, None),
+        };
+
+        bollard::models::RestartPolicy {
+            name: Some(name),
+            maximum_retry_count,
+        }
+    }
+}
+
+/// Parsed form of a `host:container[/proto]` port publish spec
+struct PortPublish {
+    host_port: String,
+    container_port: String,
+    proto: String,
+}
+
+fn parse_port_publish(spec: &str) -> Result<PortPublish, DockerError> {
+    let (port_part, proto) = match spec.rsplit_once('/') {
+        Some((p, proto)) => (p, proto.to_string()),
+        None => (spec, "tcp".to_string()),
+    };
+    let (host_port, container_port) = port_part.split_once(':').ok_or_else(|| {
+        DockerError::InvalidConfig(format!(
+            "port spec '{spec}' must be in host:container[/proto] form"
+        ))
+    })?;
+    Ok(PortPublish {
+        host_port: host_port.to_string(),
+        container_port: container_port.to_string(),
+        proto,
+    })
+}
+
+/// Parsed form of a `host_path:container_path[:options]` bind-mount spec (or a
+/// bare named volume)
+struct VolumeMount {
+    source: String,
+    destination: String,
+    read_only: bool,
+}
+
+/// `true` if every comma-separated token in `field` is a recognized bind-mount
+/// option (`ro`, `rw`, `z`, `Z`), meaning it's a trailing options field rather
+/// than a path
+fn is_mount_options_field(field: &str) -> bool {
+    !field.is_empty()
+        && field
+            .split(',')
+            .all(|token| matches!(token, "ro" | "rw" | "z" | "Z"))
+}
+
+/// Parse a Docker `-v`/`--mount`-style volume spec into its source,
+/// destination, and read-only flag. Handles a Windows drive-letter host path
+/// (`C:/Users/test:/app`), a trailing `:ro`/`:rw[,z|Z]` options field, and a
+/// bare named volume (`my-data`) with no destination.
+fn parse_volume_mount(spec: &str) -> Result<VolumeMount, DockerError> {
+    let bytes = spec.as_bytes();
+    let drive_prefix = (bytes.first().is_some_and(u8::is_ascii_alphabetic)
+        && bytes.get(1) == Some(&b':')
+        && matches!(bytes.get(2), Some(b'/') | Some(b'\\')))
+    .then(|| &spec[..2]);
+    let rest = drive_prefix.map_or(spec, |prefix| &spec[prefix.len()..]);
+
+    let mut fields: Vec<&str> = rest.splitn(3, ':').collect();
+    let read_only = match fields.last() {
+        Some(last) if fields.len() > 1 && is_mount_options_field(last) => {
+            let read_only = last.split(',').any(|token| token == "ro");
+            fields.pop();
+            read_only
+        }
+        _ => false,
+    };
+
+    let (source, destination) = match fields.as_slice() {
+        [name] if !name.is_empty() && !name.contains(['/', '\\']) => {
+            (name.to_string(), name.to_string())
+        }
+        [source, destination] => (
+            format!("{}{source}", drive_prefix.unwrap_or_default()),
+            destination.to_string(),
+        ),
+        _ => {
+            return Err(DockerError::InvalidConfig(format!(
+                "volume mount spec '{spec}' must be host:container, a bare volume name, \
+                 or host:container:options"
+            )));
+        }
+    };
+
+    Ok(VolumeMount {
+        source,
+        destination,
+        read_only,
+    })
+}
+
+/// Build the bollard container-create `Config` for a [`StartConfig`], translating
+/// ports, resource limits, restart policy, and labels into the daemon's HostConfig shape.
+fn build_container_config(
+    config: &StartConfig,
+) -> Result<bollard::container::Config<String>, DockerError> {
+    use bollard::models::{HostConfig, PortBinding};
+    use std::collections::HashMap as StdHashMap;
+
+    let mut exposed_ports: StdHashMap<String, StdHashMap<(), ()>> = StdHashMap::new();
+    let mut port_bindings: StdHashMap<String, Option<Vec<PortBinding>>> = StdHashMap::new();
+    for spec in &config.ports {
+        let publish = parse_port_publish(spec)?;
+        let key = format!("{}/{}", publish.container_port, publish.proto);
+        exposed_ports.insert(key.clone(), StdHashMap::new());
+        port_bindings
+            .entry(key)
+            .or_insert_with(|| Some(Vec::new()))
+            .get_or_insert_with(Vec::new)
+            .push(PortBinding {
+                host_ip: None,
+                host_port: Some(publish.host_port),
+            });
+    }
+
+    let binds = config
+        .volume_mounts
+        .iter()
+        .map(|spec| {
+            let mount = parse_volume_mount(spec)?;
+            Ok(if mount.read_only {
+                format!("{}:{}:ro", mount.source, mount.destination)
+            } else {
+                format!("{}:{}", mount.source, mount.destination)
+            })
+        })
+        .collect::<Result<Vec<String>, DockerError>>()?;
+
+    let host_config = HostConfig {
+        binds: Some(binds),
+        port_bindings: if port_bindings.is_empty() {
+            None
+        } else {
+            Some(port_bindings)
+        },
+        memory: config.memory_bytes.map(|b| b as i64),
+        memory_swap: config.memory_swap_bytes,
+        nano_cpus: config.nano_cpus,
+        cpu_shares: config.cpu_shares,
+        restart_policy: config.restart.map(bollard::models::RestartPolicy::from),
+        ..Default::default()
+    };
+
+    Ok(bollard::container::Config {
+        image: Some(config.image.clone()),
+        cmd: config.command.clone(),
+        env: if config.env_vars.is_empty() {
+            None
+        } else {
+            Some(config.env_vars.clone())
+        },
+        labels: if config.labels.is_empty() {
+            None
+        } else {
+            Some(config.labels.clone())
+        },
+        exposed_ports: if exposed_ports.is_empty() {
+            None
+        } else {
+            Some(exposed_ports)
+        },
+        host_config: Some(host_config),
+        ..Default::default()
+    })
 }
 
 /// Log query options for incremental fetching
@@ -63,6 +304,59 @@ pub struct LogQuery {
     pub tail_lines: Option<u64>,
     pub include_stdout: bool,
     pub include_stderr: bool,
+    /// Keep tailing new output instead of returning a one-shot snapshot
+    pub follow: bool,
+}
+
+/// Accumulated stdout/stderr demultiplexed from a Docker log/attach stream
+#[derive(Default)]
+struct DemuxedOutput {
+    stdout: Vec<String>,
+    stderr: Vec<String>,
+}
+
+/// Decode Docker's framed multiplex stream into `out`, appending any bytes that
+/// don't yet form a complete frame to `leftover` so the next chunk can pick up
+/// where this one left off. Each frame is an 8-byte header
+/// `[stream_type, 0, 0, 0, size_be_u32]` (1 = stdout, 2 = stderr) followed by
+/// exactly `size` payload bytes. Callers must detect TTY-enabled containers
+/// themselves and skip this decoder for them, since a TTY stream has no
+/// framing header and is raw passthrough.
+fn demux_frames(leftover: &mut Vec<u8>, chunk: &[u8], out: &mut DemuxedOutput) {
+    leftover.extend_from_slice(chunk);
+
+    loop {
+        if leftover.len() < 8 {
+            break;
+        }
+        let stream_type = leftover[0];
+        let size =
+            u32::from_be_bytes([leftover[4], leftover[5], leftover[6], leftover[7]]) as usize;
+        if leftover.len() < 8 + size {
+            break; // wait for the rest of the frame to arrive
+        }
+
+        let payload = &leftover[8..8 + size];
+        let text = String::from_utf8_lossy(payload).into_owned();
+        match stream_type {
+            2 => out.stderr.push(text),
+            _ => out.stdout.push(text), // stdin (0) and stdout (1) both surface as stdout
+        }
+
+        leftover.drain(0..8 + size);
+    }
+}
+
+/// Map a daemon failure to [`DockerError::ContainerNotFound`] when the daemon
+/// reported a 404 for `container_id`, falling back to the generic
+/// [`DockerError::Connection`] for anything else (auth failures, daemon down).
+fn map_container_error(container_id: &str, e: bollard::errors::Error) -> DockerError {
+    match &e {
+        bollard::errors::Error::DockerResponseServerError { status_code: 404, .. } => {
+            DockerError::ContainerNotFound(container_id.to_string())
+        }
+        _ => DockerError::Connection(e),
+    }
 }
 
 /// Log output from container
@@ -72,8 +366,131 @@ pub struct LogsOutput {
     pub timestamp: Option<OffsetDateTime>,
 }
 
+/// Which stream a `LogChunk` was demultiplexed from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogStreamKind {
+    Stdout,
+    Stderr,
+}
+
+/// One line of live container output, as produced by `DockerManager::stream_logs`
+pub struct LogChunk {
+    pub stream: LogStreamKind,
+    pub text: String,
+}
+
+/// One item produced by `DockerManager::stream_exec`
+pub enum ExecStreamItem {
+    /// A demultiplexed line of stdout/stderr output
+    Output(LogChunk),
+    /// The command finished with a non-zero exit code - always the last item
+    /// on the stream, mirroring `exec_command`'s `DockerError::ExecNonZeroExit`
+    Failed { exit_code: i64 },
+}
+
+/// Full container state, as reported by the daemon's inspect endpoint
+pub struct ContainerDetails {
+    pub status: String,
+    pub running: bool,
+    pub exit_code: Option<i64>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub oom_killed: bool,
+    pub restart_count: i64,
+    pub image: String,
+    pub ip_address: Option<String>,
+    pub ports: HashMap<String, Vec<String>>,
+    pub mounts: Vec<String>,
+    pub command: Vec<String>,
+    pub env: Vec<String>,
+}
+
+/// A single resource-usage sample for one container
+pub struct ContainerStatsSample {
+    pub cpu_percent: f64,
+    pub mem_usage_bytes: u64,
+    pub mem_limit_bytes: u64,
+    pub mem_percent: f64,
+    pub networks: HashMap<String, (u64, u64)>, // interface -> (rx_bytes, tx_bytes)
+}
+
+/// Compute the Docker-style CPU percentage from a stats sample, matching
+/// `docker stats`: delta of container CPU usage over delta of system CPU
+/// usage, scaled by the number of online CPUs.
+fn compute_cpu_percent(stats: &bollard::container::Stats) -> f64 {
+    let cpu_delta = stats.cpu_stats.cpu_usage.total_usage as f64
+        - stats.precpu_stats.cpu_usage.total_usage as f64;
+    let system_delta = stats.cpu_stats.system_cpu_usage.unwrap_or(0) as f64
+        - stats.precpu_stats.system_cpu_usage.unwrap_or(0) as f64;
+
+    if system_delta <= 0.0 {
+        return 0.0;
+    }
+
+    let online_cpus = stats.cpu_stats.online_cpus.unwrap_or_else(|| {
+        stats
+            .cpu_stats
+            .cpu_usage
+            .percpu_usage
+            .as_ref()
+            .map(|v| v.len() as u64)
+            .unwrap_or(1)
+    }) as f64;
+
+    (cpu_delta / system_delta) * online_cpus * 100.0
+}
+
+/// Build a [`ContainerStatsSample`] from a raw daemon stats reading, computing
+/// the CPU percentage and memory percentage the same way `docker stats` does.
+fn container_stats_sample(stats: &bollard::container::Stats) -> ContainerStatsSample {
+    let cpu_percent = compute_cpu_percent(stats);
+    let mem_usage_bytes = stats.memory_stats.usage.unwrap_or(0);
+    let mem_limit_bytes = stats.memory_stats.limit.unwrap_or(0);
+    let mem_percent = if mem_limit_bytes > 0 {
+        (mem_usage_bytes as f64 / mem_limit_bytes as f64) * 100.0
+    } else {
+        0.0
+    };
+
+    let networks = stats
+        .networks
+        .clone()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(iface, net)| (iface, (net.rx_bytes, net.tx_bytes)))
+        .collect();
+
+    ContainerStatsSample {
+        cpu_percent,
+        mem_usage_bytes,
+        mem_limit_bytes,
+        mem_percent,
+        networks,
+    }
+}
+
+/// Where the Docker daemon lives, following bollard's own unix-socket vs.
+/// network transport split (mirroring the `DOCKER_HOST` conventions docker
+/// itself uses: `unix:///...`, `tcp://host:port`).
+pub enum DockerEndpoint {
+    /// Local unix socket (the default, e.g. `/var/run/docker.sock`)
+    UnixSocket(std::path::PathBuf),
+    /// Plain TCP, e.g. host `127.0.0.1` port `2375`
+    Http { host: String, port: u16 },
+    /// TLS-secured TCP (`dockerd --tlsverify`), e.g. host `remote-host` port `2376`
+    Tls {
+        host: String,
+        port: u16,
+        ca: std::path::PathBuf,
+        cert: std::path::PathBuf,
+        key: std::path::PathBuf,
+    },
+}
+
+const DEFAULT_TIMEOUT_SECS: u64 = 120;
+
 impl DockerManager {
-    /// Create new Docker manager with connection to Docker daemon
+    /// Create new Docker manager bound to the default local unix socket
     pub async fn new() -> Result<Self, DockerError> {
         let docker = Docker::connect_with_socket_defaults()?;
         Ok(Self {
@@ -82,37 +499,1083 @@ impl DockerManager {
         })
     }
 
+    /// Connect to a Docker daemon over a unix socket, plain TCP, or mTLS, per `endpoint`
+    pub async fn connect(endpoint: DockerEndpoint) -> Result<Self, DockerError> {
+        let docker = match endpoint {
+            DockerEndpoint::UnixSocket(path) => Docker::connect_with_socket(
+                &path.to_string_lossy(),
+                DEFAULT_TIMEOUT_SECS,
+                bollard::API_DEFAULT_VERSION,
+            )?,
+            DockerEndpoint::Http { host, port } => Docker::connect_with_http(
+                &format!("tcp://{host}:{port}"),
+                DEFAULT_TIMEOUT_SECS,
+                bollard::API_DEFAULT_VERSION,
+            )?,
+            DockerEndpoint::Tls {
+                host,
+                port,
+                ca,
+                cert,
+                key,
+            } => Docker::connect_with_ssl(
+                &format!("tcp://{host}:{port}"),
+                &key,
+                &cert,
+                &ca,
+                DEFAULT_TIMEOUT_SECS,
+                bollard::API_DEFAULT_VERSION,
+            )?,
+        };
+        Ok(Self {
+            docker,
+            containers: Arc::new(RwLock::new(HashMap::new())),
+        })
+    }
+
     /// Start a long-running container
-    pub async fn start_container(&self, _config: StartConfig) -> Result<String, DockerError> {
-        todo!("Implement container start")
+    pub async fn start_container(&self, config: StartConfig) -> Result<String, DockerError> {
+        use bollard::container::{CreateContainerOptions, StartContainerOptions};
+
+        let container_config = build_container_config(&config)?;
+        let options = config.name.as_ref().map(|name| CreateContainerOptions {
+            name: name.clone(),
+            platform: None,
+        });
+
+        let created = self
+            .docker
+            .create_container(options, container_config)
+            .await?;
+
+        self.docker
+            .start_container(&created.id, None::<StartContainerOptions<String>>)
+            .await?;
+
+        let state = ContainerState {
+            id: created.id.clone(),
+            name: config.name.clone().unwrap_or_else(|| created.id.clone()),
+            image: config.image.clone(),
+            started_at: OffsetDateTime::now_utc(),
+            status: ContainerStatus::Running,
+        };
+        self.containers
+            .write()
+            .await
+            .insert(created.id.clone(), state);
+
+        Ok(created.id)
+    }
+
+    /// Fetch logs from container (can be called repeatedly). When `query.follow` is
+    /// set, keeps reading until the daemon closes the stream (e.g. the container
+    /// exits) instead of returning after the current backlog.
+    pub async fn get_logs(&self, query: LogQuery) -> Result<LogsOutput, DockerError> {
+        use bollard::container::LogsOptions;
+        use tokio_stream::StreamExt;
+
+        let options = LogsOptions::<String> {
+            follow: query.follow,
+            stdout: query.include_stdout,
+            stderr: query.include_stderr,
+            tail: query
+                .tail_lines
+                .map(|n| n.to_string())
+                .unwrap_or_else(|| "all".to_string()),
+            since: query.since.map(|t| t.unix_timestamp()).unwrap_or(0),
+            ..Default::default()
+        };
+
+        let tty = self.is_tty_enabled(&query.container_id).await?;
+        let mut stream = self.docker.logs(&query.container_id, Some(options));
+        let mut leftover = Vec::new();
+        let mut out = DemuxedOutput::default();
+
+        while let Some(chunk) = stream.next().await {
+            let bytes = chunk?.into_bytes();
+            if tty {
+                out.stdout.push(String::from_utf8_lossy(&bytes).into_owned());
+            } else {
+                demux_frames(&mut leftover, &bytes, &mut out);
+            }
+        }
+
+        Ok(LogsOutput {
+            stdout: out.stdout,
+            stderr: out.stderr,
+            timestamp: Some(OffsetDateTime::now_utc()),
+        })
+    }
+
+    /// Whether a container was started with a TTY attached, in which case its
+    /// log/attach stream has no stdout/stderr framing header.
+    async fn is_tty_enabled(&self, container_id: &str) -> Result<bool, DockerError> {
+        let details = self.docker.inspect_container(container_id, None).await?;
+        Ok(details.config.and_then(|c| c.tty).unwrap_or(false))
+    }
+
+    /// Tail-follow a container's logs (`docker logs -f`), demultiplexing
+    /// stdout/stderr as chunks arrive. Unlike `get_logs`, this never returns a
+    /// batch: it keeps yielding until the daemon closes the stream or the
+    /// caller stops polling it, making it suitable for push-based delivery
+    /// (e.g. forwarding each chunk onto an SSE session as it arrives).
+    pub fn stream_logs(&self, container_id: &str) -> impl tokio_stream::Stream<Item = LogChunk> {
+        use bollard::container::LogsOptions;
+        use tokio_stream::StreamExt;
+        use tokio_stream::wrappers::UnboundedReceiverStream;
+
+        let docker = self.docker.clone();
+        let container_id = container_id.to_string();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let options = LogsOptions::<String> {
+                follow: true,
+                stdout: true,
+                stderr: true,
+                tail: "0".to_string(),
+                ..Default::default()
+            };
+
+            let tty = docker
+                .inspect_container(&container_id, None)
+                .await
+                .ok()
+                .and_then(|d| d.config.and_then(|c| c.tty))
+                .unwrap_or(false);
+
+            let mut stream = docker.logs(&container_id, Some(options));
+            let mut leftover = Vec::new();
+
+            while let Some(chunk) = stream.next().await {
+                let Ok(output) = chunk else { break };
+                let bytes = output.into_bytes();
+
+                if tty {
+                    let text = String::from_utf8_lossy(&bytes).into_owned();
+                    if tx
+                        .send(LogChunk {
+                            stream: LogStreamKind::Stdout,
+                            text,
+                        })
+                        .is_err()
+                    {
+                        break;
+                    }
+                    continue;
+                }
+
+                let mut out = DemuxedOutput::default();
+                demux_frames(&mut leftover, &bytes, &mut out);
+
+                for text in out.stdout {
+                    if tx
+                        .send(LogChunk {
+                            stream: LogStreamKind::Stdout,
+                            text,
+                        })
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                for text in out.stderr {
+                    if tx
+                        .send(LogChunk {
+                            stream: LogStreamKind::Stderr,
+                            text,
+                        })
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        });
+
+        UnboundedReceiverStream::new(rx)
     }
 
-    /// Fetch logs from container (can be called repeatedly)
-    pub async fn get_logs(&self, _query: LogQuery) -> Result<LogsOutput, DockerError> {
-        todo!("Implement log fetching")
+    /// Fetch the full daemon-reported state of a container: status, exit code,
+    /// resolved image, network settings, mounts, and effective command/env.
+    pub async fn inspect(&self, container_id: &str) -> Result<ContainerDetails, DockerError> {
+        let details = self.docker.inspect_container(container_id, None).await?;
+
+        let state = details.state.unwrap_or_default();
+        let config = details.config.unwrap_or_default();
+        let network_settings = details.network_settings.unwrap_or_default();
+
+        let ip_address = network_settings
+            .ip_address
+            .filter(|ip| !ip.is_empty())
+            .or_else(|| {
+                network_settings.networks.as_ref().and_then(|networks| {
+                    networks
+                        .values()
+                        .find_map(|n| n.ip_address.clone().filter(|ip| !ip.is_empty()))
+                })
+            });
+
+        let ports = network_settings
+            .ports
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(container_port, bindings)| {
+                let hosts = bindings
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|b| {
+                        let host_ip = b.host_ip.unwrap_or_default();
+                        b.host_port.map(|port| format!("{host_ip}:{port}"))
+                    })
+                    .collect();
+                (container_port, hosts)
+            })
+            .collect();
+
+        let mounts = details
+            .mounts
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|m| match (m.source, m.destination) {
+                (Some(src), Some(dst)) => Some(format!("{src}:{dst}")),
+                _ => None,
+            })
+            .collect();
+
+        Ok(ContainerDetails {
+            status: state.status.map(|s| s.to_string()).unwrap_or_default(),
+            running: state.running.unwrap_or(false),
+            exit_code: state.exit_code,
+            started_at: state.started_at,
+            finished_at: state.finished_at,
+            oom_killed: state.oom_killed.unwrap_or(false),
+            restart_count: details.restart_count.unwrap_or(0),
+            image: config.image.unwrap_or_default(),
+            ip_address,
+            ports,
+            mounts,
+            command: config.cmd.unwrap_or_default(),
+            env: config.env.unwrap_or_default(),
+        })
     }
 
     /// Execute one-off command in container
+    /// Run a one-off command in a running container via `docker exec`,
+    /// demultiplexing the attached stream the same way `get_logs` does. The
+    /// exec session is never allocated a TTY, so its output is always framed.
+    /// A non-zero exit is reported as [`DockerError::ExecNonZeroExit`] rather
+    /// than folded into the stdout/stderr text, so callers can branch on it.
     pub async fn exec_command(
         &self,
-        _container_id: &str,
-        _command: &str,
+        container_id: &str,
+        command: &str,
     ) -> Result<String, DockerError> {
-        todo!("Implement exec")
+        use bollard::exec::{CreateExecOptions, StartExecResults};
+        use tokio_stream::StreamExt;
+
+        let exec = self
+            .docker
+            .create_exec(
+                container_id,
+                CreateExecOptions {
+                    cmd: Some(vec!["sh".to_string(), "-c".to_string(), command.to_string()]),
+                    attach_stdout: Some(true),
+                    attach_stderr: Some(true),
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(|e| map_container_error(container_id, e))?;
+
+        let mut out = DemuxedOutput::default();
+
+        if let StartExecResults::Attached { mut output, .. } =
+            self.docker.start_exec(&exec.id, None).await?
+        {
+            let mut leftover = Vec::new();
+            while let Some(chunk) = output.next().await {
+                demux_frames(&mut leftover, &chunk?.into_bytes(), &mut out);
+            }
+        }
+
+        let exit_code = self.docker.inspect_exec(&exec.id).await?.exit_code;
+        if let Some(code) = exit_code.filter(|&code| code != 0) {
+            return Err(DockerError::ExecNonZeroExit {
+                container_id: container_id.to_string(),
+                exit_code: code,
+                message: if out.stderr.is_empty() {
+                    out.stdout.concat()
+                } else {
+                    out.stderr.concat()
+                },
+            });
+        }
+
+        Ok(out.stdout.concat())
+    }
+
+    /// Run a one-off command in a running container, yielding each demultiplexed
+    /// line as it arrives instead of buffering the whole output like
+    /// `exec_command`. Suitable for push-based delivery (e.g. forwarding each
+    /// line onto a streamed HTTP response as the command runs). Unlike
+    /// `exec_command`, a non-zero exit can't be reported by returning an
+    /// `Err` - the output's already been streamed to the caller by the time
+    /// the exit code is known - so it's reported as a final
+    /// [`ExecStreamItem::Failed`] item instead of the stream just ending.
+    pub fn stream_exec(
+        &self,
+        container_id: &str,
+        command: &str,
+    ) -> impl tokio_stream::Stream<Item = ExecStreamItem> {
+        use bollard::exec::{CreateExecOptions, StartExecResults};
+        use tokio_stream::StreamExt;
+        use tokio_stream::wrappers::UnboundedReceiverStream;
+
+        let docker = self.docker.clone();
+        let container_id = container_id.to_string();
+        let command = command.to_string();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let exec = match docker
+                .create_exec(
+                    &container_id,
+                    CreateExecOptions {
+                        cmd: Some(vec!["sh".to_string(), "-c".to_string(), command]),
+                        attach_stdout: Some(true),
+                        attach_stderr: Some(true),
+                        ..Default::default()
+                    },
+                )
+                .await
+            {
+                Ok(exec) => exec,
+                Err(_) => return,
+            };
+
+            let Ok(StartExecResults::Attached { mut output, .. }) =
+                docker.start_exec(&exec.id, None).await
+            else {
+                return;
+            };
+
+            let mut leftover = Vec::new();
+            while let Some(chunk) = output.next().await {
+                let Ok(frame) = chunk else { break };
+                let mut out = DemuxedOutput::default();
+                demux_frames(&mut leftover, &frame.into_bytes(), &mut out);
+
+                for text in out.stdout {
+                    if tx
+                        .send(ExecStreamItem::Output(LogChunk {
+                            stream: LogStreamKind::Stdout,
+                            text,
+                        }))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+                for text in out.stderr {
+                    if tx
+                        .send(ExecStreamItem::Output(LogChunk {
+                            stream: LogStreamKind::Stderr,
+                            text,
+                        }))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+
+            if let Ok(details) = docker.inspect_exec(&exec.id).await {
+                if let Some(exit_code) = details.exit_code.filter(|&code| code != 0) {
+                    let _ = tx.send(ExecStreamItem::Failed { exit_code });
+                }
+            }
+        });
+
+        UnboundedReceiverStream::new(rx)
     }
 
     /// Stop and remove container
-    pub async fn stop_container(&self, _container_id: &str) -> Result<(), DockerError> {
-        todo!("Implement container stop")
+    pub async fn stop_container(&self, container_id: &str) -> Result<(), DockerError> {
+        use bollard::container::{RemoveContainerOptions, StopContainerOptions};
+
+        self.docker
+            .stop_container(container_id, None::<StopContainerOptions>)
+            .await
+            .map_err(|e| map_container_error(container_id, e))?;
+
+        self.docker
+            .remove_container(
+                container_id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    ..Default::default()
+                }),
+            )
+            .await
+            .map_err(|e| map_container_error(container_id, e))?;
+
+        if let Some(state) = self.containers.write().await.get_mut(container_id) {
+            state.status = ContainerStatus::Stopped;
+        }
+
+        Ok(())
     }
 
-    /// List all tracked containers
-    pub fn list_containers(&self) -> Vec<ContainerState> {
-        todo!("Implement list")
+    /// List all containers this server has started and is tracking in memory
+    /// (not a live daemon query - see `list_containers_from_daemon` for that)
+    pub async fn list_containers(&self) -> Vec<ContainerState> {
+        self.containers.read().await.values().cloned().collect()
     }
 
-    /// Get container state by ID
-    pub fn get_container(&self, _container_id: &str) -> Option<ContainerState> {
-        todo!("Implement get")
+    /// Take a single CPU/memory/network usage sample for a container
+    pub async fn get_stats(&self, container_id: &str) -> Result<ContainerStatsSample, DockerError> {
+        use bollard::container::StatsOptions;
+        use tokio_stream::StreamExt;
+
+        let mut stream = self.docker.stats(
+            container_id,
+            Some(StatsOptions {
+                stream: false,
+                one_shot: true,
+            }),
+        );
+
+        let stats = stream
+            .next()
+            .await
+            .ok_or_else(|| DockerError::ContainerNotFound(container_id.to_string()))??;
+
+        Ok(container_stats_sample(&stats))
+    }
+
+    /// Tail-follow a container's CPU/memory/network usage (`docker stats`),
+    /// yielding one [`ContainerStatsSample`] per sample the daemon emits.
+    /// Unlike `get_stats`, this never returns a single reading: it keeps
+    /// yielding until the daemon closes the stream or the caller stops
+    /// polling it, making it suitable for push-based delivery (e.g.
+    /// forwarding each sample onto an SSE session as it arrives).
+    pub fn stream_stats(
+        &self,
+        container_id: &str,
+    ) -> impl tokio_stream::Stream<Item = ContainerStatsSample> {
+        use bollard::container::StatsOptions;
+        use tokio_stream::StreamExt;
+        use tokio_stream::wrappers::UnboundedReceiverStream;
+
+        let docker = self.docker.clone();
+        let container_id = container_id.to_string();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let mut stream = docker.stats(
+                &container_id,
+                Some(StatsOptions {
+                    stream: true,
+                    one_shot: false,
+                }),
+            );
+
+            while let Some(chunk) = stream.next().await {
+                let Ok(stats) = chunk else { break };
+                let sample = container_stats_sample(&stats);
+                if tx.send(sample).is_err() {
+                    break; // no subscribers left on this session
+                }
+            }
+        });
+
+        UnboundedReceiverStream::new(rx)
+    }
+
+    /// Get a tracked container's in-memory state by ID (not a live daemon
+    /// query - see `inspect` for that)
+    pub async fn get_container(&self, container_id: &str) -> Option<ContainerState> {
+        self.containers.read().await.get(container_id).cloned()
+    }
+
+    /// List containers directly from the daemon, including ones this server
+    /// didn't start or that have since exited, per `all`/`filters`.
+    pub async fn list_containers_from_daemon(
+        &self,
+        all: bool,
+        filters: HashMap<String, Vec<String>>,
+    ) -> Result<Vec<ContainerSummary>, DockerError> {
+        use bollard::container::ListContainersOptions;
+
+        let containers = self
+            .docker
+            .list_containers(Some(ListContainersOptions {
+                all,
+                filters,
+                ..Default::default()
+            }))
+            .await?;
+
+        Ok(containers
+            .into_iter()
+            .map(|c| ContainerSummary {
+                id: c.id.unwrap_or_default(),
+                names: c
+                    .names
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(|n| n.trim_start_matches('/').to_string())
+                    .collect(),
+                image: c.image.unwrap_or_default(),
+                state: c.state.unwrap_or_default(),
+                status: c.status.unwrap_or_default(),
+                ports: c
+                    .ports
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|p| {
+                        let host_port = p.public_port?;
+                        let host_ip = p.ip.unwrap_or_else(|| "0.0.0.0".to_string());
+                        let proto = p
+                            .typ
+                            .map(|t| format!("{t:?}").to_lowercase())
+                            .unwrap_or_else(|| "tcp".to_string());
+                        Some(format!("{host_ip}:{host_port}->{}/{proto}", p.private_port))
+                    })
+                    .collect(),
+            })
+            .collect())
+    }
+}
+
+/// One container entry as reported by the daemon's `/containers/json`
+pub struct ContainerSummary {
+    pub id: String,
+    pub names: Vec<String>,
+    pub image: String,
+    pub state: String,
+    pub status: String,
+    pub ports: Vec<String>,
+}
+
+/// Summary of one local image
+pub struct ImageSummary {
+    pub repo_tags: Vec<String>,
+    pub id: String,
+    pub size_bytes: u64,
+    pub created: String,
+}
+
+impl DockerManager {
+    /// Pull an image (and optional tag) from a registry, reporting per-layer
+    /// progress lines and the final resolved digest.
+    pub async fn pull_image(
+        &self,
+        image: &str,
+        tag: Option<&str>,
+    ) -> Result<(Option<String>, Vec<String>), DockerError> {
+        self.pull_image_with_progress(image, tag, None, None).await
+    }
+
+    /// Same as [`Self::pull_image`], but forwards each progress line to
+    /// `progress` as it arrives instead of only returning them buffered at
+    /// the end, so a caller can relay them as notifications while the pull
+    /// is still in flight. `registry_auth` is an opaque pre-authenticated
+    /// identity token for a private registry, not a username:password pair.
+    pub async fn pull_image_with_progress(
+        &self,
+        image: &str,
+        tag: Option<&str>,
+        registry_auth: Option<&str>,
+        progress: Option<&tokio::sync::mpsc::UnboundedSender<String>>,
+    ) -> Result<(Option<String>, Vec<String>), DockerError> {
+        use bollard::auth::DockerCredentials;
+        use bollard::image::CreateImageOptions;
+        use tokio_stream::StreamExt;
+
+        let options = CreateImageOptions {
+            from_image: image.to_string(),
+            tag: tag.unwrap_or("latest").to_string(),
+            ..Default::default()
+        };
+
+        let credentials = registry_auth.map(|token| DockerCredentials {
+            identitytoken: Some(token.to_string()),
+            ..Default::default()
+        });
+
+        let mut stream = self.docker.create_image(Some(options), None, credentials);
+        let mut lines = Vec::new();
+        let mut digest = None;
+
+        while let Some(info) = stream.next().await {
+            let info = info?;
+            if let Some(status) = &info.status {
+                let line = match (&info.id, &info.progress) {
+                    (Some(id), Some(p)) => format!("{id}: {status} {p}"),
+                    (Some(id), None) => format!("{id}: {status}"),
+                    (None, _) => status.clone(),
+                };
+                if let Some(tx) = progress {
+                    let _ = tx.send(line.clone());
+                }
+                lines.push(line);
+            }
+            if let Some(d) = info.status.as_deref().and_then(|s| s.strip_prefix("Digest: ")) {
+                digest = Some(d.to_string());
+            }
+        }
+
+        Ok((digest, lines))
+    }
+
+    /// List local images, optionally filtered by a reference substring
+    pub async fn list_images(
+        &self,
+        filter: Option<&str>,
+    ) -> Result<Vec<ImageSummary>, DockerError> {
+        use bollard::image::ListImagesOptions;
+
+        let images = self
+            .docker
+            .list_images(Some(ListImagesOptions::<String> {
+                all: false,
+                ..Default::default()
+            }))
+            .await?;
+
+        Ok(images
+            .into_iter()
+            .filter(|img| match filter {
+                Some(f) => img.repo_tags.iter().any(|t| t.contains(f)),
+                None => true,
+            })
+            .map(|img| ImageSummary {
+                repo_tags: img.repo_tags,
+                id: img.id,
+                size_bytes: img.size.max(0) as u64,
+                created: OffsetDateTime::from_unix_timestamp(img.created)
+                    .map(|t| {
+                        t.format(&time::format_description::well_known::Rfc3339)
+                            .unwrap_or_default()
+                    })
+                    .unwrap_or_default(),
+            })
+            .collect())
+    }
+
+    /// Build an image from a Dockerfile and context directory, tarring the
+    /// context in-memory and streaming build output lines back.
+    pub async fn build_image(
+        &self,
+        context_path: &std::path::Path,
+        dockerfile: &str,
+        tag: Option<&str>,
+        build_args: &HashMap<String, String>,
+        nocache: bool,
+    ) -> Result<(Option<String>, Vec<String>), DockerError> {
+        self.build_image_with_progress(context_path, dockerfile, tag, build_args, nocache, None)
+            .await
+    }
+
+    /// Same as [`Self::build_image`], but forwards each line of daemon
+    /// output to `progress` as it arrives instead of only returning it
+    /// buffered at the end, so a caller can relay it as notifications while
+    /// the build is still in flight.
+    pub async fn build_image_with_progress(
+        &self,
+        context_path: &std::path::Path,
+        dockerfile: &str,
+        tag: Option<&str>,
+        build_args: &HashMap<String, String>,
+        nocache: bool,
+        progress: Option<&tokio::sync::mpsc::UnboundedSender<String>>,
+    ) -> Result<(Option<String>, Vec<String>), DockerError> {
+        use bollard::image::BuildImageOptions;
+        use tokio_stream::StreamExt;
+
+        let tarball = tar_directory(context_path)?;
+
+        let options = BuildImageOptions {
+            dockerfile: dockerfile.to_string(),
+            t: tag.unwrap_or_default().to_string(),
+            buildargs: build_args.clone(),
+            nocache,
+            ..Default::default()
+        };
+
+        let mut stream = self
+            .docker
+            .build_image(options, None, Some(tarball.into()));
+        let mut output = Vec::new();
+        let mut image_id = None;
+
+        while let Some(info) = stream.next().await {
+            let info = info?;
+            if let Some(stream_line) = info.stream {
+                if let Some(tx) = progress {
+                    let _ = tx.send(stream_line.clone());
+                }
+                output.push(stream_line);
+            }
+            if let Some(aux) = info.aux {
+                if let Some(id) = aux.id {
+                    image_id = Some(id);
+                }
+            }
+        }
+
+        Ok((image_id, output))
+    }
+}
+
+/// A single decoded daemon lifecycle event
+pub struct DockerEvent {
+    pub event_type: String,
+    pub action: String,
+    pub actor_id: String,
+    pub attributes: HashMap<String, String>,
+    pub time: String,
+}
+
+impl DockerManager {
+    /// Collect daemon events matching `filters`, stopping once `count` events
+    /// have arrived or `timeout_secs` elapses, whichever comes first. This
+    /// gives request/response MCP tool calls a way to "wait for" a lifecycle
+    /// event instead of polling `inspect` in a loop.
+    pub async fn collect_events(
+        &self,
+        since: Option<OffsetDateTime>,
+        until: Option<OffsetDateTime>,
+        filters: HashMap<String, Vec<String>>,
+        count: Option<u64>,
+        timeout_secs: u64,
+    ) -> Result<Vec<DockerEvent>, DockerError> {
+        use bollard::system::EventsOptions;
+        use tokio_stream::StreamExt;
+
+        let options = EventsOptions {
+            since: since.map(|t| t.unix_timestamp().to_string()),
+            until: until.map(|t| t.unix_timestamp().to_string()),
+            filters,
+        };
+
+        let mut stream = self.docker.events(Some(options));
+        let mut events = Vec::new();
+        let deadline = tokio::time::sleep(std::time::Duration::from_secs(timeout_secs));
+        tokio::pin!(deadline);
+
+        loop {
+            if let Some(limit) = count {
+                if events.len() as u64 >= limit {
+                    break;
+                }
+            }
+
+            tokio::select! {
+                _ = &mut deadline => break,
+                item = stream.next() => {
+                    match item {
+                        Some(Ok(msg)) => events.push(decode_event(msg)),
+                        Some(Err(e)) => return Err(e.into()),
+                        None => break,
+                    }
+                }
+            }
+        }
+
+        Ok(events)
+    }
+
+    /// Subscribe to the daemon's lifecycle event stream matching `filters`,
+    /// yielding one [`DockerEvent`] as each arrives. Unlike `collect_events`,
+    /// this never stops on its own: it keeps yielding until the daemon closes
+    /// the stream or the caller stops polling it, making it suitable for
+    /// push-based delivery (e.g. forwarding each event onto an SSE session).
+    /// As a side effect, `start`/`die`/`oom` events for tracked containers
+    /// update their [`ContainerStatus`] in `self.containers`, so `docker_list`
+    /// reflects exits without the caller having to poll `inspect`.
+    pub fn stream_events(
+        &self,
+        filters: HashMap<String, Vec<String>>,
+    ) -> impl tokio_stream::Stream<Item = DockerEvent> {
+        use bollard::system::EventsOptions;
+        use tokio_stream::StreamExt;
+        use tokio_stream::wrappers::UnboundedReceiverStream;
+
+        let docker = self.docker.clone();
+        let containers = self.containers.clone();
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+
+        tokio::spawn(async move {
+            let options = EventsOptions {
+                since: None,
+                until: None,
+                filters,
+            };
+            let mut stream = docker.events(Some(options));
+
+            while let Some(chunk) = stream.next().await {
+                let Ok(msg) = chunk else { break };
+                let event = decode_event(msg);
+                update_tracked_status(&containers, &event).await;
+                if tx.send(event).is_err() {
+                    break; // no subscribers left on this session
+                }
+            }
+        });
+
+        UnboundedReceiverStream::new(rx)
+    }
+}
+
+/// Decode a raw daemon event message into a [`DockerEvent`]
+fn decode_event(msg: bollard::models::EventMessage) -> DockerEvent {
+    DockerEvent {
+        event_type: msg
+            .typ
+            .map(|t| format!("{t:?}").to_lowercase())
+            .unwrap_or_default(),
+        action: msg.action.unwrap_or_default(),
+        actor_id: msg
+            .actor
+            .as_ref()
+            .and_then(|a| a.id.clone())
+            .unwrap_or_default(),
+        attributes: msg
+            .actor
+            .and_then(|a| a.attributes)
+            .unwrap_or_default(),
+        time: msg
+            .time
+            .and_then(|secs| OffsetDateTime::from_unix_timestamp(secs).ok())
+            .map(|t| {
+                t.format(&time::format_description::well_known::Rfc3339)
+                    .unwrap_or_default()
+            })
+            .unwrap_or_default(),
+    }
+}
+
+/// Update a tracked container's status from a `container`-typed lifecycle
+/// event, so `docker_list` reflects exits/OOM-kills without manual polling.
+async fn update_tracked_status(
+    containers: &Arc<RwLock<HashMap<String, ContainerState>>>,
+    event: &DockerEvent,
+) {
+    if event.event_type != "container" {
+        return;
+    }
+
+    let mut containers = containers.write().await;
+    let Some(state) = containers.get_mut(&event.actor_id) else {
+        return;
+    };
+
+    match event.action.as_str() {
+        "start" => state.status = ContainerStatus::Running,
+        "die" => {
+            let exit_code = event
+                .attributes
+                .get("exitCode")
+                .and_then(|c| c.parse::<i32>().ok())
+                .unwrap_or(0);
+            state.status = ContainerStatus::Exited(exit_code);
+        }
+        "oom" => state.status = ContainerStatus::Error("OOM killed".to_string()),
+        "stop" => state.status = ContainerStatus::Stopped,
+        _ => {}
+    }
+}
+
+/// Tar up a build-context directory into an in-memory archive, as required by
+/// the daemon's `/build` endpoint.
+fn tar_directory(dir: &std::path::Path) -> Result<Vec<u8>, DockerError> {
+    let mut archive = tar::Builder::new(Vec::new());
+    archive
+        .append_dir_all(".", dir)
+        .map_err(|e| DockerError::InvalidConfig(format!("failed to tar build context: {e}")))?;
+    archive
+        .into_inner()
+        .map_err(|e| DockerError::InvalidConfig(format!("failed to finalize tar archive: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(stream_type: u8, payload: &[u8]) -> Vec<u8> {
+        let mut frame = vec![stream_type, 0, 0, 0];
+        frame.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    #[test]
+    fn test_demux_frames_single_stdout() {
+        let chunk = frame(1, b"hello");
+        let mut leftover = Vec::new();
+        let mut out = DemuxedOutput::default();
+
+        demux_frames(&mut leftover, &chunk, &mut out);
+
+        assert_eq!(out.stdout, vec!["hello".to_string()]);
+        assert!(out.stderr.is_empty());
+        assert!(leftover.is_empty());
+    }
+
+    #[test]
+    fn test_demux_frames_routes_stderr() {
+        let chunk = frame(2, b"oops");
+        let mut leftover = Vec::new();
+        let mut out = DemuxedOutput::default();
+
+        demux_frames(&mut leftover, &chunk, &mut out);
+
+        assert_eq!(out.stderr, vec!["oops".to_string()]);
+        assert!(out.stdout.is_empty());
+    }
+
+    #[test]
+    fn test_demux_frames_interleaved() {
+        let mut chunk = frame(1, b"out-line");
+        chunk.extend(frame(2, b"err-line"));
+        let mut leftover = Vec::new();
+        let mut out = DemuxedOutput::default();
+
+        demux_frames(&mut leftover, &chunk, &mut out);
+
+        assert_eq!(out.stdout, vec!["out-line".to_string()]);
+        assert_eq!(out.stderr, vec!["err-line".to_string()]);
+    }
+
+    #[test]
+    fn test_demux_frames_partial_header_carries_over() {
+        let chunk = frame(1, b"hello");
+        // Split mid-header: only the first 3 bytes arrive in this read.
+        let mut leftover = Vec::new();
+        let mut out = DemuxedOutput::default();
+
+        demux_frames(&mut leftover, &chunk[..3], &mut out);
+        assert!(out.stdout.is_empty());
+        assert_eq!(leftover.len(), 3);
+
+        demux_frames(&mut leftover, &chunk[3..], &mut out);
+        assert_eq!(out.stdout, vec!["hello".to_string()]);
+        assert!(leftover.is_empty());
+    }
+
+    #[test]
+    fn test_demux_frames_partial_payload_carries_over() {
+        let chunk = frame(1, b"hello world");
+        let mut leftover = Vec::new();
+        let mut out = DemuxedOutput::default();
+
+        // Header plus a few payload bytes in the first read.
+        demux_frames(&mut leftover, &chunk[..10], &mut out);
+        assert!(out.stdout.is_empty());
+
+        demux_frames(&mut leftover, &chunk[10..], &mut out);
+        assert_eq!(out.stdout, vec!["hello world".to_string()]);
+        assert!(leftover.is_empty());
+    }
+
+    #[test]
+    fn test_restart_policy_parse() {
+        assert_eq!("no".parse::<RestartPolicy>().unwrap(), RestartPolicy::No);
+        assert_eq!(
+            "always".parse::<RestartPolicy>().unwrap(),
+            RestartPolicy::Always
+        );
+        assert_eq!(
+            "unless-stopped".parse::<RestartPolicy>().unwrap(),
+            RestartPolicy::UnlessStopped
+        );
+        assert_eq!(
+            "on-failure:3".parse::<RestartPolicy>().unwrap(),
+            RestartPolicy::OnFailure { max_retries: 3 }
+        );
+        assert!("bogus".parse::<RestartPolicy>().is_err());
+    }
+
+    #[test]
+    fn test_restart_policy_into_bollard() {
+        let policy: bollard::models::RestartPolicy =
+            RestartPolicy::OnFailure { max_retries: 5 }.into();
+        assert_eq!(
+            policy.name,
+            Some(bollard::models::RestartPolicyNameEnum::ON_FAILURE)
+        );
+        assert_eq!(policy.maximum_retry_count, Some(5));
+    }
+
+    #[test]
+    fn test_parse_volume_mount_simple() {
+        let mount = parse_volume_mount("/host/path:/container/path").unwrap();
+
+        assert_eq!(mount.source, "/host/path");
+        assert_eq!(mount.destination, "/container/path");
+        assert!(!mount.read_only);
+    }
+
+    #[test]
+    fn test_parse_volume_mount_windows_drive_letter() {
+        let mount = parse_volume_mount("C:/Users/test:/app").unwrap();
+
+        assert_eq!(mount.source, "C:/Users/test");
+        assert_eq!(mount.destination, "/app");
+    }
+
+    #[test]
+    fn test_parse_volume_mount_read_only_option() {
+        let mount = parse_volume_mount("/host/path:/container/path:ro").unwrap();
+
+        assert_eq!(mount.source, "/host/path");
+        assert_eq!(mount.destination, "/container/path");
+        assert!(mount.read_only);
+    }
+
+    #[test]
+    fn test_parse_volume_mount_named_volume() {
+        let mount = parse_volume_mount("my-data").unwrap();
+
+        assert_eq!(mount.source, "my-data");
+        assert_eq!(mount.destination, "my-data");
+        assert!(!mount.read_only);
+    }
+
+    #[test]
+    fn test_parse_volume_mount_invalid() {
+        // A Windows host path with no destination field can't be told apart
+        // from a plain source, so it's rejected rather than guessed at.
+        let result = parse_volume_mount("C:/Users/test");
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_parse_port_publish_defaults_to_tcp() {
+        let publish = parse_port_publish("8080:80").unwrap();
+
+        assert_eq!(publish.host_port, "8080");
+        assert_eq!(publish.container_port, "80");
+        assert_eq!(publish.proto, "tcp");
+    }
+
+    #[test]
+    fn test_parse_port_publish_explicit_proto() {
+        let publish = parse_port_publish("53:53/udp").unwrap();
+
+        assert_eq!(publish.host_port, "53");
+        assert_eq!(publish.container_port, "53");
+        assert_eq!(publish.proto, "udp");
+    }
+
+    #[test]
+    fn test_parse_port_publish_missing_colon_is_invalid() {
+        let result = parse_port_publish("8080");
+
+        assert!(result.is_err());
     }
 }
@@ -7,28 +7,71 @@
 //! - Security: Origin validation, localhost binding
 
 use crate::docker_manager::DockerManager;
-use crate::mcp_server::{JsonRpcRequest, JsonRpcResponse};
+use crate::mcp_server::{JsonRpcRequest, JsonRpcResponse, NotificationSink, SubscriptionRegistry, ToolExecState};
 use axum::{
     Router,
-    extract::State,
-    http::{HeaderMap, StatusCode, header},
+    extract::{FromRequestParts, State},
+    http::{HeaderMap, StatusCode, header, request::Parts},
     response::{IntoResponse, Response, Sse, sse::Event},
     routing::{delete, get, post},
 };
+use async_trait::async_trait;
 use serde_json::{Value, json};
-use std::{collections::HashMap, convert::Infallible, net::SocketAddr, sync::Arc, time::Duration};
-use tokio::sync::{RwLock, broadcast};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+use tokio::sync::{Mutex, RwLock, broadcast};
 use tokio_stream::StreamExt;
 use uuid::Uuid;
 
 /// Session state for a connected client
 #[derive(Debug)]
-#[allow(dead_code)] // id and created_at will be used for session management
 pub struct Session {
+    #[allow(dead_code)] // kept for logging/debugging, not looked up by value
     pub id: String,
     pub created_at: time::OffsetDateTime,
+    /// Unix timestamp (seconds) of the last request this session successfully
+    /// handled, updated by [`Session::touch`]. `is_expired` measures idle time
+    /// against this rather than `created_at`, so a client that keeps polling
+    /// stays alive indefinitely instead of being swept exactly `session_ttl`
+    /// after `initialize`.
+    last_active_at: AtomicU64,
     /// Broadcast channel for sending SSE events to this session
     pub tx: broadcast::Sender<SseMessage>,
+    /// Counter for out-of-band notification event ids (progress frames,
+    /// subscription pushes) pushed onto `tx` via [`HttpToolExecState`]
+    next_event_id: Arc<AtomicU64>,
+    /// Background subscriptions this session started via
+    /// `docker_subscribe_logs`/`docker_subscribe_stats`, cancellable by
+    /// `docker_unsubscribe`
+    subscriptions: SubscriptionRegistry,
+    next_subscription_id: Arc<AtomicU64>,
+}
+
+impl Session {
+    /// Whether this session has sat idle for longer than `ttl` since its last
+    /// successfully handled request (or since creation, if it's never had one)
+    fn is_expired(&self, ttl: Duration) -> bool {
+        let last_active = self.last_active_at.load(Ordering::Relaxed) as i64;
+        let idle_secs = time::OffsetDateTime::now_utc().unix_timestamp() - last_active;
+        idle_secs.max(0) as u64 > ttl.as_secs()
+    }
+
+    /// Record that this session just handled a request, resetting its idle
+    /// clock
+    fn touch(&self) {
+        self.last_active_at.store(
+            time::OffsetDateTime::now_utc().unix_timestamp() as u64,
+            Ordering::Relaxed,
+        );
+    }
 }
 
 /// Message sent over SSE
@@ -45,14 +88,21 @@ pub struct AppState {
     pub sessions: Arc<RwLock<HashMap<String, Session>>>,
     /// Allowed origins for CORS/security (None = allow all for local dev)
     pub allowed_origins: Option<Vec<String>>,
+    /// How long a session may go without a fresh request before it's swept
+    pub session_ttl: Duration,
 }
 
 impl AppState {
     pub fn new(manager: DockerManager) -> Self {
+        Self::with_session_ttl(manager, DEFAULT_SESSION_TTL)
+    }
+
+    pub fn with_session_ttl(manager: DockerManager, session_ttl: Duration) -> Self {
         Self {
             manager,
             sessions: Arc::new(RwLock::new(HashMap::new())),
             allowed_origins: None,
+            session_ttl,
         }
     }
 
@@ -64,7 +114,11 @@ impl AppState {
         let session = Session {
             id: session_id.clone(),
             created_at: time::OffsetDateTime::now_utc(),
+            last_active_at: AtomicU64::new(time::OffsetDateTime::now_utc().unix_timestamp() as u64),
             tx,
+            next_event_id: Arc::new(AtomicU64::new(1)),
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            next_subscription_id: Arc::new(AtomicU64::new(1)),
         };
 
         self.sessions
@@ -75,13 +129,31 @@ impl AppState {
         session_id
     }
 
-    /// Get a session by ID
+    /// Get a session by ID, unless it has expired
     pub async fn get_session(&self, session_id: &str) -> Option<broadcast::Sender<SseMessage>> {
-        self.sessions
-            .read()
-            .await
+        let sessions = self.sessions.read().await;
+        let session = sessions.get(session_id).filter(|s| !s.is_expired(self.session_ttl))?;
+        session.touch();
+        Some(session.tx.clone())
+    }
+
+    /// Build the [`ToolExecState`] `dispatch_tool_call` needs to run a tool
+    /// call for this session, unless the session doesn't exist or has
+    /// expired
+    async fn tool_exec_state(&self, session_id: &str) -> Option<HttpToolExecState> {
+        let sessions = self.sessions.read().await;
+        let session = sessions
             .get(session_id)
-            .map(|s| s.tx.clone())
+            .filter(|s| !s.is_expired(self.session_ttl))?;
+        session.touch();
+
+        Some(HttpToolExecState {
+            manager: self.manager.clone(),
+            tx: session.tx.clone(),
+            next_event_id: session.next_event_id.clone(),
+            subscriptions: session.subscriptions.clone(),
+            next_subscription_id: session.next_subscription_id.clone(),
+        })
     }
 
     /// Remove a session
@@ -93,23 +165,142 @@ impl AppState {
         removed
     }
 
-    /// Check if session exists
+    /// Check if a session exists and hasn't expired, touching it if so -
+    /// every non-`initialize` POST request passes through here, making this
+    /// the one place that has to record activity for methods (like `ping`)
+    /// that never reach `get_session`/`tool_exec_state`
     pub async fn session_exists(&self, session_id: &str) -> bool {
-        self.sessions.read().await.contains_key(session_id)
+        let sessions = self.sessions.read().await;
+        match sessions.get(session_id).filter(|s| !s.is_expired(self.session_ttl)) {
+            Some(session) => {
+                session.touch();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Drop any session that has outlived its TTL, along with its broadcast
+    /// channel, so idle clients don't pin memory forever
+    async fn sweep_expired_sessions(&self) {
+        let mut sessions = self.sessions.write().await;
+        let before = sessions.len();
+        sessions.retain(|_, session| !session.is_expired(self.session_ttl));
+        let swept = before - sessions.len();
+        if swept > 0 {
+            tracing::info!(swept, "Swept expired sessions");
+        }
+    }
+}
+
+/// Implements [`ToolExecState`] so HTTP's `tools/call` handling dispatches
+/// through the same [`crate::mcp_server::dispatch_tool_call`] chokepoint the
+/// stdio transport uses: progress frames and `docker_subscribe_logs`/
+/// `docker_subscribe_stats` pushes go out as SSE events on this session's
+/// channel instead of framed stdout lines.
+struct HttpToolExecState {
+    manager: DockerManager,
+    tx: broadcast::Sender<SseMessage>,
+    next_event_id: Arc<AtomicU64>,
+    subscriptions: SubscriptionRegistry,
+    next_subscription_id: Arc<AtomicU64>,
+}
+
+impl ToolExecState for HttpToolExecState {
+    fn manager(&self) -> &DockerManager {
+        &self.manager
+    }
+
+    fn notification_sink(&self) -> Option<NotificationSink> {
+        Some(NotificationSink::Sse {
+            tx: self.tx.clone(),
+            next_event_id: self.next_event_id.clone(),
+        })
+    }
+
+    fn subscriptions(&self) -> &SubscriptionRegistry {
+        &self.subscriptions
+    }
+
+    fn next_subscription_id(&self) -> u64 {
+        self.next_subscription_id.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// Whether the client's `Accept` header admits an SSE response, extracted
+/// up front so `handle_post` can pick between buffering one JSON body and
+/// opening an SSE stream on the same dual-mode endpoint, per the MCP
+/// Streamable HTTP transport spec. Modeled on the external `axum-extra`
+/// `Accept` extractor, scoped down to the one distinction this transport
+/// cares about.
+pub struct ExtractAccept {
+    pub prefers_event_stream: bool,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for ExtractAccept
+where
+    S: Send + Sync,
+{
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let prefers_event_stream = parts
+            .headers
+            .get(header::ACCEPT)
+            .and_then(|v| v.to_str().ok())
+            .is_some_and(|accept| {
+                accept
+                    .split(',')
+                    .any(|part| part.trim().starts_with("text/event-stream"))
+            });
+
+        Ok(Self {
+            prefers_event_stream,
+        })
     }
 }
 
 /// Custom header names for MCP
 const MCP_SESSION_ID_HEADER: &str = "mcp-session-id";
-#[allow(dead_code)] // Will be used for protocol version negotiation
 const MCP_PROTOCOL_VERSION_HEADER: &str = "mcp-protocol-version";
 
+/// MCP protocol versions this server understands, checked against the
+/// `Mcp-Protocol-Version` header on every POST request
+const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &["2024-11-05"];
+
+/// Default session TTL when the caller doesn't override it via
+/// [`AppState::with_session_ttl`]
+const DEFAULT_SESSION_TTL: Duration = Duration::from_secs(30 * 60);
+
+/// How often the background sweep checks for expired sessions
+const SESSION_SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
 /// Run the HTTP transport server
 pub async fn run(
     manager: DockerManager,
     addr: SocketAddr,
 ) -> Result<(), Box<dyn std::error::Error>> {
-    let state = AppState::new(manager);
+    run_with_session_ttl(manager, addr, DEFAULT_SESSION_TTL).await
+}
+
+/// Run the HTTP transport server with an explicit session TTL (exposed so
+/// callers can make it configurable, e.g. via a CLI flag)
+pub async fn run_with_session_ttl(
+    manager: DockerManager,
+    addr: SocketAddr,
+    session_ttl: Duration,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let state = AppState::with_session_ttl(manager, session_ttl);
+
+    let sweep_state = state.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(SESSION_SWEEP_INTERVAL);
+        loop {
+            interval.tick().await;
+            sweep_state.sweep_expired_sessions().await;
+        }
+    });
 
     let app = Router::new()
         .route("/mcp", post(handle_post))
@@ -154,13 +345,44 @@ fn get_session_id(headers: &HeaderMap) -> Option<String> {
         .map(|s| s.to_string())
 }
 
+/// Reject a `Mcp-Protocol-Version` the server doesn't speak. A missing header
+/// is left to the client's discretion (older clients may omit it).
+fn validate_protocol_version(headers: &HeaderMap) -> Result<(), Response> {
+    let Some(version) = headers.get(MCP_PROTOCOL_VERSION_HEADER).and_then(|v| v.to_str().ok())
+    else {
+        return Ok(());
+    };
+
+    if SUPPORTED_PROTOCOL_VERSIONS.contains(&version) {
+        Ok(())
+    } else {
+        Err((
+            StatusCode::BAD_REQUEST,
+            format!(
+                "Unsupported Mcp-Protocol-Version '{version}', supported: {}",
+                SUPPORTED_PROTOCOL_VERSIONS.join(", ")
+            ),
+        )
+            .into_response())
+    }
+}
+
 /// Handle POST requests - client sends JSON-RPC messages
-async fn handle_post(State(state): State<AppState>, headers: HeaderMap, body: String) -> Response {
+async fn handle_post(
+    State(state): State<AppState>,
+    accept: ExtractAccept,
+    headers: HeaderMap,
+    body: String,
+) -> Response {
     // Validate Origin
     if !validate_origin(&headers, &state.allowed_origins) {
         return (StatusCode::FORBIDDEN, "Invalid origin").into_response();
     }
 
+    if let Err(response) = validate_protocol_version(&headers) {
+        return response;
+    }
+
     tracing::info!(request = %body, "Received HTTP POST request");
 
     // Parse JSON-RPC request
@@ -195,23 +417,43 @@ async fn handle_post(State(state): State<AppState>, headers: HeaderMap, body: St
             .into_response();
     }
 
-    // For non-initialize requests, log session status (validation optional for dev)
-    let session_id = get_session_id(&headers);
-    match &session_id {
+    // Every other request must carry a live session
+    let session_id = match get_session_id(&headers) {
+        Some(sid) if state.session_exists(&sid).await => sid,
         Some(sid) => {
-            if state.session_exists(sid).await {
-                tracing::debug!(session_id = %sid, "Valid session");
-            } else {
-                tracing::warn!(session_id = %sid, "Stale session ID, proceeding anyway");
-            }
+            tracing::warn!(session_id = %sid, "Rejected request with unknown or expired session");
+            let error = JsonRpcResponse::error(request.id, -32001, "Session not found or expired");
+            return (
+                StatusCode::NOT_FOUND,
+                [(header::CONTENT_TYPE, "application/json")],
+                serde_json::to_string(&error).unwrap_or_default(),
+            )
+                .into_response();
         }
         None => {
-            tracing::debug!("No session ID provided, proceeding without session");
+            tracing::warn!("Rejected request with no Mcp-Session-Id header");
+            let error = JsonRpcResponse::error(request.id, -32001, "Missing Mcp-Session-Id header");
+            return (
+                StatusCode::NOT_FOUND,
+                [(header::CONTENT_TYPE, "application/json")],
+                serde_json::to_string(&error).unwrap_or_default(),
+            )
+                .into_response();
         }
+    };
+    let session_id = Some(session_id);
+
+    // A client that accepts event-stream responses gets a `tools/call` result
+    // streamed incrementally on this same POST, instead of one buffered body -
+    // the only requests worth streaming, since every other method resolves
+    // in a single round-trip anyway.
+    if accept.prefers_event_stream && request.method == "tools/call" {
+        return handle_tools_call_sse(&state, request.id, request.params, session_id.as_deref())
+            .await;
     }
 
     // Handle the request
-    let response = handle_json_rpc_request(&state, request).await;
+    let response = handle_json_rpc_request(&state, request, session_id.as_deref()).await;
 
     (
         StatusCode::OK,
@@ -222,194 +464,452 @@ async fn handle_post(State(state): State<AppState>, headers: HeaderMap, body: St
 }
 
 /// Handle a JSON-RPC request (reuses mcp_server logic)
-async fn handle_json_rpc_request(state: &AppState, request: JsonRpcRequest) -> JsonRpcResponse {
+async fn handle_json_rpc_request(
+    state: &AppState,
+    request: JsonRpcRequest,
+    session_id: Option<&str>,
+) -> JsonRpcResponse {
     match request.method.as_str() {
         "initialize" => crate::mcp_server::handle_initialize(request.id),
-        "tools/list" => crate::mcp_server::handle_tools_list(request.id),
-        "tools/call" => handle_tools_call(state, request.id, request.params).await,
+        "tools/list" => handle_tools_list(request.id),
+        "tools/call" => handle_tools_call(state, request.id, request.params, session_id).await,
         "ping" => JsonRpcResponse::success(request.id, json!({})),
         _ => JsonRpcResponse::error(request.id, -32601, "Method not found"),
     }
 }
 
-/// Handle tools/call - delegates to docker operations
-async fn handle_tools_call(state: &AppState, id: Option<Value>, params: Value) -> JsonRpcResponse {
-    use crate::docker_manager::{LogQuery, StartConfig};
-    use crate::tools::{
-        DockerExecArgs, DockerLogsArgs, DockerLogsResult, DockerRunArgs, DockerRunResult,
-        DockerStopArgs, ToolResult,
-    };
+/// Tool list shared with the stdio transport, plus `docker_logs_follow`,
+/// which only makes sense where a session's SSE stream exists to push onto.
+fn handle_tools_list(id: Option<Value>) -> JsonRpcResponse {
+    let mut response = crate::mcp_server::handle_tools_list(id);
+
+    if let Some(tools) = response
+        .result
+        .as_mut()
+        .and_then(|result| result.get_mut("tools"))
+        .and_then(|tools| tools.as_array_mut())
+    {
+        tools.push(json!({
+            "name": "docker_logs_follow",
+            "description": "Tail-follow a container's logs, pushing each line to this session's SSE stream",
+            "inputSchema": crate::mcp_server::schema_for::<crate::tools::DockerLogsFollowArgs>(),
+        }));
+    }
+
+    response
+}
+
+/// Handle tools/call. The streaming tools that only make sense on this
+/// transport (`docker_stats`/`docker_events` with `stream: true`, and
+/// `docker_logs_follow`, which has no stdio equivalent) push their output
+/// onto the session's SSE channel directly; everything else - including
+/// `docker_subscribe_logs`/`docker_subscribe_stats`/`docker_unsubscribe` and
+/// `docker_batch` - is dispatched through the same
+/// [`crate::mcp_server::handle_tools_call`] chokepoint the stdio transport
+/// uses, via [`HttpToolExecState`].
+async fn handle_tools_call(
+    state: &AppState,
+    id: Option<Value>,
+    params: Value,
+    session_id: Option<&str>,
+) -> JsonRpcResponse {
+    use crate::docker_manager::LogStreamKind;
+    use crate::tools::{DockerEventsArgs, DockerLogsFollowArgs, DockerStatsArgs, ToolResult};
 
     let tool_name = params.get("name").and_then(|v| v.as_str()).unwrap_or("");
     let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
 
-    tracing::info!(tool = %tool_name, arguments = %arguments, "Executing tool (HTTP)");
+    let tool_error = |id: Option<Value>, msg: String| {
+        tracing::error!(tool = %tool_name, error = %msg, "Tool execution failed (HTTP)");
+        JsonRpcResponse::success(
+            id,
+            json!({
+                "content": [{ "type": "text", "text": format!("Error: {msg}") }],
+                "isError": true
+            }),
+        )
+    };
 
-    let result: Result<String, String> = match tool_name {
-        "docker_run" => {
-            let args: DockerRunArgs = match serde_json::from_value(arguments) {
-                Ok(a) => a,
-                Err(e) => {
-                    return JsonRpcResponse::error(id, -32602, format!("Invalid params: {}", e));
-                }
+    if tool_name == "docker_stats" {
+        let args: DockerStatsArgs = match serde_json::from_value(arguments.clone()) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, -32602, format!("Invalid params: {e}")),
+        };
+
+        if args.stream.unwrap_or(false) {
+            return match session_id {
+                Some(sid) => match state.get_session(sid).await {
+                    Some(tx) => {
+                        let manager = state.manager.clone();
+                        let container_id = args.container_id.clone();
+
+                        tokio::spawn(async move {
+                            let mut stream = manager.stream_stats(&container_id);
+                            let mut sample_num = 0u64;
+
+                            while let Some(sample) = stream.next().await {
+                                sample_num += 1;
+                                let data = json!({
+                                    "container_id": container_id,
+                                    "cpu_percent": sample.cpu_percent,
+                                    "mem_usage_bytes": sample.mem_usage_bytes,
+                                    "mem_limit_bytes": sample.mem_limit_bytes,
+                                    "mem_percent": sample.mem_percent,
+                                    "networks": sample
+                                        .networks
+                                        .into_iter()
+                                        .map(|(iface, (rx_bytes, tx_bytes))| {
+                                            (iface, crate::tools::NetworkIoSample { rx_bytes, tx_bytes })
+                                        })
+                                        .collect::<HashMap<_, _>>(),
+                                })
+                                .to_string();
+
+                                if tx
+                                    .send(SseMessage { event_id: sample_num.to_string(), data })
+                                    .is_err()
+                                {
+                                    break; // no subscribers left on this session
+                                }
+                            }
+                        });
+
+                        let result = ToolResult {
+                            success: true,
+                            output: format!(
+                                "Streaming stats for container {} over SSE",
+                                args.container_id
+                            ),
+                            error: None,
+                        };
+                        JsonRpcResponse::success(
+                            id,
+                            json!({
+                                "content": [{
+                                    "type": "text",
+                                    "text": serde_json::to_string(&result).unwrap_or_default()
+                                }]
+                            }),
+                        )
+                    }
+                    None => tool_error(id, format!("No active session '{sid}'")),
+                },
+                None => tool_error(
+                    id,
+                    "docker_stats with stream=true requires an Mcp-Session-Id header".to_string(),
+                ),
             };
+        }
+    }
 
-            let config = StartConfig {
-                image: args.image,
-                command: args.command,
-                env_vars: args.env_vars,
-                volume_mounts: args
-                    .volume_mounts
-                    .iter()
-                    .filter_map(|m| {
-                        let parts: Vec<&str> = m.splitn(2, ':').collect();
-                        if parts.len() == 2 {
-                            Some((parts[0].to_string(), parts[1].to_string()))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect(),
-                name: args.name,
+    if tool_name == "docker_events" {
+        let args: DockerEventsArgs = match serde_json::from_value(arguments.clone()) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, -32602, format!("Invalid params: {e}")),
+        };
+
+        if args.stream.unwrap_or(false) {
+            return match session_id {
+                Some(sid) => match state.get_session(sid).await {
+                    Some(tx) => {
+                        let manager = state.manager.clone();
+                        let filters = args.filters;
+
+                        tokio::spawn(async move {
+                            let mut stream = manager.stream_events(filters);
+                            let mut event_num = 0u64;
+
+                            while let Some(event) = stream.next().await {
+                                event_num += 1;
+                                let data = json!({
+                                    "type": event.event_type,
+                                    "action": event.action,
+                                    "actor_id": event.actor_id,
+                                    "attributes": event.attributes,
+                                    "time": event.time,
+                                })
+                                .to_string();
+
+                                if tx
+                                    .send(SseMessage { event_id: event_num.to_string(), data })
+                                    .is_err()
+                                {
+                                    break; // no subscribers left on this session
+                                }
+                            }
+                        });
+
+                        let result = ToolResult {
+                            success: true,
+                            output: "Subscribed to daemon events over SSE".to_string(),
+                            error: None,
+                        };
+                        JsonRpcResponse::success(
+                            id,
+                            json!({
+                                "content": [{
+                                    "type": "text",
+                                    "text": serde_json::to_string(&result).unwrap_or_default()
+                                }]
+                            }),
+                        )
+                    }
+                    None => tool_error(id, format!("No active session '{sid}'")),
+                },
+                None => tool_error(
+                    id,
+                    "docker_events with stream=true requires an Mcp-Session-Id header".to_string(),
+                ),
             };
+        }
+    }
+
+    if tool_name == "docker_logs_follow" {
+        let args: DockerLogsFollowArgs = match serde_json::from_value(arguments) {
+            Ok(a) => a,
+            Err(e) => return JsonRpcResponse::error(id, -32602, format!("Invalid params: {e}")),
+        };
+
+        return match session_id {
+            Some(sid) => match state.get_session(sid).await {
+                Some(tx) => {
+                    let manager = state.manager.clone();
+                    let container_id = args.container_id.clone();
+
+                    tokio::spawn(async move {
+                        let mut stream = manager.stream_logs(&container_id);
+                        let mut line = 0u64;
+
+                        while let Some(chunk) = stream.next().await {
+                            line += 1;
+                            let stream_name = match chunk.stream {
+                                LogStreamKind::Stdout => "stdout",
+                                LogStreamKind::Stderr => "stderr",
+                            };
+                            let data = json!({
+                                "container_id": container_id,
+                                "stream": stream_name,
+                                "text": chunk.text,
+                            })
+                            .to_string();
+
+                            if tx.send(SseMessage { event_id: line.to_string(), data }).is_err() {
+                                break; // no subscribers left on this session
+                            }
+                        }
+                    });
 
-            match state.manager.start_container(config).await {
-                Ok(container_id) => {
-                    let result = DockerRunResult {
+                    let result = ToolResult {
                         success: true,
-                        container_id: container_id.clone(),
-                        message: format!("Container started: {}", container_id),
+                        output: format!(
+                            "Following logs for container {} over SSE",
+                            args.container_id
+                        ),
+                        error: None,
                     };
-                    serde_json::to_string(&result).map_err(|e| e.to_string())
+                    JsonRpcResponse::success(
+                        id,
+                        json!({
+                            "content": [{
+                                "type": "text",
+                                "text": serde_json::to_string(&result).unwrap_or_default()
+                            }]
+                        }),
+                    )
                 }
-                Err(e) => Err(e.to_string()),
-            }
-        }
-        "docker_logs" => {
-            let args: DockerLogsArgs = match serde_json::from_value(arguments) {
+                None => tool_error(id, format!("No active session '{sid}'")),
+            },
+            None => tool_error(id, "docker_logs_follow requires an Mcp-Session-Id header".to_string()),
+        };
+    }
+
+    // Every other tool - including docker_subscribe_logs/docker_subscribe_stats/
+    // docker_unsubscribe and docker_batch, which used to be hard-rejected here -
+    // goes through the same dispatch the stdio transport uses.
+    let Some(sid) = session_id else {
+        return tool_error(id, format!("{tool_name} requires an Mcp-Session-Id header"));
+    };
+
+    match state.tool_exec_state(sid).await {
+        Some(exec_state) => crate::mcp_server::handle_tools_call(&exec_state, id, params).await,
+        None => tool_error(id, format!("No active session '{sid}'")),
+    }
+}
+
+/// A boxed stream of SSE events, used to let `handle_tools_call_sse`'s match
+/// arms return differently-shaped underlying streams through one return type.
+type SseEventStream =
+    std::pin::Pin<Box<dyn tokio_stream::Stream<Item = Result<Event, Infallible>> + Send>>;
+
+/// Wrap a single `Event` in a one-shot stream, for tool calls that don't push
+/// incremental output but still need to answer an `Accept: text/event-stream`
+/// request with a stream rather than a buffered body.
+fn single_event_stream(event: Event) -> SseEventStream {
+    let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+    let _ = tx.send(Ok(event));
+    Box::pin(tokio_stream::wrappers::UnboundedReceiverStream::new(rx))
+}
+
+fn sse_response(stream: SseEventStream) -> Response {
+    Sse::new(stream)
+        .keep_alive(
+            axum::response::sse::KeepAlive::new()
+                .interval(Duration::from_secs(30))
+                .text("ping"),
+        )
+        .into_response()
+}
+
+/// Stream a `tools/call` result back on the same POST connection, for a
+/// client that sent `Accept: text/event-stream` (the dual-mode single
+/// endpoint the MCP Streamable HTTP spec describes). Push-style tools
+/// (`docker_exec`, `docker_logs_follow`, and `docker_stats`/`docker_events`
+/// with `stream: true`) forward each chunk as its own event as the daemon
+/// produces it; every other tool call runs through the normal buffered path
+/// and is delivered as a single event once it resolves.
+async fn handle_tools_call_sse(
+    state: &AppState,
+    id: Option<Value>,
+    params: Value,
+    session_id: Option<&str>,
+) -> Response {
+    use crate::docker_manager::{ExecStreamItem, LogStreamKind};
+    use crate::tools::{DockerEventsArgs, DockerExecArgs, DockerLogsFollowArgs, DockerStatsArgs};
+
+    let tool_name = params.get("name").and_then(|v| v.as_str()).unwrap_or("");
+    let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
+
+    let invalid_params = |e: serde_json::Error| {
+        let response = JsonRpcResponse::error(id.clone(), -32602, format!("Invalid params: {e}"));
+        sse_response(single_event_stream(Event::default().data(
+            serde_json::to_string(&response).unwrap_or_default(),
+        )))
+    };
+
+    match tool_name {
+        "docker_exec" => {
+            let args: DockerExecArgs = match serde_json::from_value(arguments) {
                 Ok(a) => a,
-                Err(e) => {
-                    return JsonRpcResponse::error(id, -32602, format!("Invalid params: {}", e));
-                }
+                Err(e) => return invalid_params(e),
             };
 
-            let query = LogQuery {
-                container_id: args.container_id,
-                since: args.since.and_then(|s| {
-                    time::OffsetDateTime::parse(&s, &time::format_description::well_known::Rfc3339)
-                        .ok()
-                }),
-                tail_lines: args.tail_lines,
-                include_stdout: args.stdout.unwrap_or(true),
-                include_stderr: args.stderr.unwrap_or(true),
+            let manager = state.manager.clone();
+            let mut line = 0u64;
+            let stream = manager
+                .stream_exec(&args.container_id, &args.command)
+                .map(move |item| {
+                    line += 1;
+                    match item {
+                        ExecStreamItem::Output(chunk) => {
+                            let stream_name = match chunk.stream {
+                                LogStreamKind::Stdout => "stdout",
+                                LogStreamKind::Stderr => "stderr",
+                            };
+                            let data =
+                                json!({ "stream": stream_name, "text": chunk.text }).to_string();
+                            Ok::<_, Infallible>(Event::default().id(line.to_string()).data(data))
+                        }
+                        ExecStreamItem::Failed { exit_code } => {
+                            let data = json!({
+                                "error": format!("Command exited with code {exit_code}"),
+                                "exit_code": exit_code,
+                            })
+                            .to_string();
+                            Ok::<_, Infallible>(
+                                Event::default().id(line.to_string()).event("error").data(data),
+                            )
+                        }
+                    }
+                });
+
+            sse_response(Box::pin(stream))
+        }
+        "docker_logs_follow" => {
+            let args: DockerLogsFollowArgs = match serde_json::from_value(arguments) {
+                Ok(a) => a,
+                Err(e) => return invalid_params(e),
             };
 
-            match state.manager.get_logs(query).await {
-                Ok(logs) => {
-                    let result = DockerLogsResult {
-                        success: true,
-                        stdout: logs.stdout,
-                        stderr: logs.stderr,
-                        timestamp: logs.timestamp.map(|t| {
-                            t.format(&time::format_description::well_known::Rfc3339)
-                                .unwrap_or_default()
-                        }),
+            let manager = state.manager.clone();
+            let mut line = 0u64;
+            let stream = manager
+                .stream_logs(&args.container_id)
+                .map(move |chunk| {
+                    line += 1;
+                    let stream_name = match chunk.stream {
+                        LogStreamKind::Stdout => "stdout",
+                        LogStreamKind::Stderr => "stderr",
                     };
-                    serde_json::to_string(&result).map_err(|e| e.to_string())
-                }
-                Err(e) => Err(e.to_string()),
-            }
+                    let data = json!({ "stream": stream_name, "text": chunk.text }).to_string();
+                    Ok::<_, Infallible>(Event::default().id(line.to_string()).data(data))
+                });
+
+            sse_response(Box::pin(stream))
         }
-        "docker_exec" => {
-            let args: DockerExecArgs = match serde_json::from_value(arguments) {
+        "docker_stats" => {
+            let args: DockerStatsArgs = match serde_json::from_value(arguments) {
                 Ok(a) => a,
-                Err(e) => {
-                    return JsonRpcResponse::error(id, -32602, format!("Invalid params: {}", e));
-                }
+                Err(e) => return invalid_params(e),
             };
 
-            match state
-                .manager
-                .exec_command(&args.container_id, &args.command)
-                .await
-            {
-                Ok(output) => {
-                    let result = ToolResult {
-                        success: true,
-                        output,
-                        error: None,
-                    };
-                    serde_json::to_string(&result).map_err(|e| e.to_string())
-                }
-                Err(e) => Err(e.to_string()),
+            if !args.stream.unwrap_or(false) {
+                let response = handle_tools_call(state, id, params, session_id).await;
+                return sse_response(single_event_stream(Event::default().data(
+                    serde_json::to_string(&response).unwrap_or_default(),
+                )));
             }
+
+            let manager = state.manager.clone();
+            let mut sample_num = 0u64;
+            let stream = manager.stream_stats(&args.container_id).map(move |sample| {
+                sample_num += 1;
+                let data = json!({
+                    "cpu_percent": sample.cpu_percent,
+                    "mem_usage_bytes": sample.mem_usage_bytes,
+                    "mem_limit_bytes": sample.mem_limit_bytes,
+                    "mem_percent": sample.mem_percent,
+                })
+                .to_string();
+                Ok::<_, Infallible>(Event::default().id(sample_num.to_string()).data(data))
+            });
+
+            sse_response(Box::pin(stream))
         }
-        "docker_stop" => {
-            let args: DockerStopArgs = match serde_json::from_value(arguments) {
+        "docker_events" => {
+            let args: DockerEventsArgs = match serde_json::from_value(arguments) {
                 Ok(a) => a,
-                Err(e) => {
-                    return JsonRpcResponse::error(id, -32602, format!("Invalid params: {}", e));
-                }
+                Err(e) => return invalid_params(e),
             };
 
-            match state.manager.stop_container(&args.container_id).await {
-                Ok(()) => {
-                    let result = ToolResult {
-                        success: true,
-                        output: format!("Container {} stopped", args.container_id),
-                        error: None,
-                    };
-                    serde_json::to_string(&result).map_err(|e| e.to_string())
-                }
-                Err(e) => Err(e.to_string()),
+            if !args.stream.unwrap_or(false) {
+                let response = handle_tools_call(state, id, params, session_id).await;
+                return sse_response(single_event_stream(Event::default().data(
+                    serde_json::to_string(&response).unwrap_or_default(),
+                )));
             }
-        }
-        "docker_list" => {
-            let containers = state.manager.list_containers().await;
-            let list: Vec<Value> = containers.iter().map(|c| {
-                json!({
-                    "id": c.id,
-                    "name": c.name,
-                    "image": c.image,
-                    "started_at": c.started_at.format(&time::format_description::well_known::Rfc3339).unwrap_or_default(),
-                    "status": match &c.status {
-                        crate::docker_manager::ContainerStatus::Running => "running",
-                        crate::docker_manager::ContainerStatus::Stopped => "stopped",
-                        crate::docker_manager::ContainerStatus::Exited(_) => "exited",
-                        crate::docker_manager::ContainerStatus::Error(_) => "error",
-                    }
+
+            let manager = state.manager.clone();
+            let mut event_num = 0u64;
+            let stream = manager.stream_events(args.filters).map(move |event| {
+                event_num += 1;
+                let data = json!({
+                    "type": event.event_type,
+                    "action": event.action,
+                    "actor_id": event.actor_id,
+                    "attributes": event.attributes,
+                    "time": event.time,
                 })
-            }).collect();
-            serde_json::to_string(&json!({ "containers": list })).map_err(|e| e.to_string())
-        }
-        _ => Err(format!("Unknown tool: {}", tool_name)),
-    };
+                .to_string();
+                Ok::<_, Infallible>(Event::default().id(event_num.to_string()).data(data))
+            });
 
-    match result {
-        Ok(content) => {
-            tracing::info!(tool = %tool_name, "Tool execution succeeded (HTTP)");
-            JsonRpcResponse::success(
-                id,
-                json!({
-                    "content": [{
-                        "type": "text",
-                        "text": content
-                    }]
-                }),
-            )
+            sse_response(Box::pin(stream))
         }
-        Err(e) => {
-            tracing::error!(tool = %tool_name, error = %e, "Tool execution failed (HTTP)");
-            JsonRpcResponse::success(
-                id,
-                json!({
-                    "content": [{
-                        "type": "text",
-                        "text": format!("Error: {}", e)
-                    }],
-                    "isError": true
-                }),
-            )
+        _ => {
+            let response = handle_tools_call(state, id, params, session_id).await;
+            sse_response(single_event_stream(Event::default().data(
+                serde_json::to_string(&response).unwrap_or_default(),
+            )))
         }
     }
 }
@@ -487,6 +987,75 @@ async fn handle_delete(State(state): State<AppState>, headers: HeaderMap) -> Res
 mod tests {
     use super::*;
 
+    async fn test_state(session_ttl: Duration) -> AppState {
+        AppState::with_session_ttl(
+            DockerManager::new().await.expect("building a client doesn't require a reachable daemon"),
+            session_ttl,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_fresh_session_is_not_expired() {
+        let state = test_state(Duration::from_secs(60)).await;
+        let session_id = state.create_session().await;
+
+        assert!(state.session_exists(&session_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_session_past_ttl_since_last_activity_is_expired() {
+        let state = test_state(Duration::from_secs(60)).await;
+        let session_id = state.create_session().await;
+
+        {
+            let sessions = state.sessions.read().await;
+            let session = sessions.get(&session_id).unwrap();
+            session
+                .last_active_at
+                .store(time::OffsetDateTime::now_utc().unix_timestamp() as u64 - 61, Ordering::Relaxed);
+        }
+
+        assert!(!state.session_exists(&session_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_activity_resets_idle_clock_past_original_ttl() {
+        let state = test_state(Duration::from_secs(60)).await;
+        let session_id = state.create_session().await;
+
+        // Back-date creation so an absolute-lifetime check would already have
+        // expired this session, then touch it via a fresh request - it should
+        // still be alive, since expiry tracks idle time, not session age.
+        {
+            let mut sessions = state.sessions.write().await;
+            let session = sessions.get_mut(&session_id).unwrap();
+            session.created_at -= Duration::from_secs(120);
+        }
+
+        assert!(state.session_exists(&session_id).await);
+    }
+
+    #[tokio::test]
+    async fn test_sweep_expired_sessions_removes_only_idle_ones() {
+        let state = test_state(Duration::from_secs(60)).await;
+        let fresh = state.create_session().await;
+        let idle = state.create_session().await;
+
+        {
+            let sessions = state.sessions.read().await;
+            sessions
+                .get(&idle)
+                .unwrap()
+                .last_active_at
+                .store(time::OffsetDateTime::now_utc().unix_timestamp() as u64 - 61, Ordering::Relaxed);
+        }
+
+        state.sweep_expired_sessions().await;
+
+        assert!(state.sessions.read().await.contains_key(&fresh));
+        assert!(!state.sessions.read().await.contains_key(&idle));
+    }
+
     #[test]
     fn test_validate_origin_no_header() {
         let headers = HeaderMap::new();
@@ -521,4 +1090,24 @@ mod tests {
         let allowed = Some(vec!["https://myapp.com".to_string()]);
         assert!(validate_origin(&headers, &allowed));
     }
+
+    #[test]
+    fn test_validate_protocol_version_missing_is_allowed() {
+        let headers = HeaderMap::new();
+        assert!(validate_protocol_version(&headers).is_ok());
+    }
+
+    #[test]
+    fn test_validate_protocol_version_supported() {
+        let mut headers = HeaderMap::new();
+        headers.insert(MCP_PROTOCOL_VERSION_HEADER, "2024-11-05".parse().unwrap());
+        assert!(validate_protocol_version(&headers).is_ok());
+    }
+
+    #[test]
+    fn test_validate_protocol_version_unsupported_rejected() {
+        let mut headers = HeaderMap::new();
+        headers.insert(MCP_PROTOCOL_VERSION_HEADER, "1999-01-01".parse().unwrap());
+        assert!(validate_protocol_version(&headers).is_err());
+    }
 }
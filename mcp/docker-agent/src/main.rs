@@ -3,7 +3,9 @@
 //! Long-running Docker container manager accessible via MCP protocol
 
 mod docker_manager;
+mod http_transport;
 mod mcp_server;
+mod service;
 mod tools;
 
 use argh::FromArgs;
@@ -17,6 +19,35 @@ struct Args {
     #[argh(option, default = "String::from(\"/var/run/docker.sock\")")]
     socket: String,
 
+    /// remote Docker daemon, e.g. tcp://host:2376 or unix:///path/to.sock
+    /// (overrides --socket; falls back to the DOCKER_HOST env var)
+    #[argh(option)]
+    host: Option<String>,
+
+    /// which transport to serve the MCP protocol over: "stdio" or "http"
+    #[argh(option, default = "String::from(\"stdio\")")]
+    transport: String,
+
+    /// address to bind when --transport http is used
+    #[argh(option, default = "String::from(\"127.0.0.1:8080\")")]
+    addr: String,
+
+    /// how long an HTTP session may sit idle before it's expired (--transport http only)
+    #[argh(option, default = "1800")]
+    session_ttl_secs: u64,
+
+    /// client certificate for TLS-secured daemons (requires --tls-key and --tls-ca)
+    #[argh(option)]
+    tls_cert: Option<String>,
+
+    /// client private key for TLS-secured daemons
+    #[argh(option)]
+    tls_key: Option<String>,
+
+    /// CA certificate for TLS-secured daemons
+    #[argh(option)]
+    tls_ca: Option<String>,
+
     /// log level (default: info)
     #[argh(option, default = "String::from(\"info\")")]
     log_level: String,
@@ -39,8 +70,102 @@ async fn main() -> std::result::Result<(), Box<dyn std::error::Error>> {
 
     tracing::info!("Starting Docker Agent MCP Server");
 
-    let manager = docker_manager::DockerManager::new().await?;
-    mcp_server::run(manager).await?;
+    let manager = connect(&args).await?;
+
+    let transport = match args.transport.as_str() {
+        "http" => Transport::Http {
+            addr: args.addr.parse()?,
+            session_ttl: std::time::Duration::from_secs(args.session_ttl_secs),
+        },
+        _ => Transport::Stdio,
+    };
+
+    serve(manager, transport).await
+}
+
+/// Which wire format the MCP protocol is served over; `serve` is the single
+/// transport-agnostic entry point so `main` doesn't need to know how either
+/// transport's request loop works
+enum Transport {
+    /// Newline-delimited JSON-RPC over stdin/stdout, one request per line
+    Stdio,
+    /// Streamable HTTP: POSTed JSON-RPC bodies, with SSE for subscriptions
+    Http {
+        addr: std::net::SocketAddr,
+        session_ttl: std::time::Duration,
+    },
+}
+
+/// Run the MCP server to completion over the given transport. This is the
+/// one place that picks which transport's request loop to hand `manager`
+/// to; `mcp_server` and `http_transport` still each own their transport's
+/// framing (reading requests, writing responses/notifications back out), but
+/// both route `tools/call` through the same `mcp_server::dispatch_tool_call`/
+/// `handle_tools_call` chokepoint rather than each hand-rolling tool
+/// dispatch.
+async fn serve(
+    manager: docker_manager::DockerManager,
+    transport: Transport,
+) -> std::result::Result<(), Box<dyn std::error::Error>> {
+    match transport {
+        Transport::Stdio => mcp_server::run(manager).await,
+        Transport::Http { addr, session_ttl } => {
+            http_transport::run_with_session_ttl(manager, addr, session_ttl).await
+        }
+    }
+}
+
+/// Build the Docker daemon connection requested by the CLI flags: a plain
+/// unix socket by default, or `--host` (or the `DOCKER_HOST` env var) over
+/// TCP/mTLS when given, so the agent can manage containers on a remote host.
+async fn connect(args: &Args) -> Result<docker_manager::DockerManager, Box<dyn std::error::Error>> {
+    use docker_manager::{DockerEndpoint, DockerManager};
+
+    let host = args.host.clone().or_else(|| std::env::var("DOCKER_HOST").ok());
+
+    let manager = match host {
+        None => DockerManager::connect(DockerEndpoint::UnixSocket(args.socket.clone().into())).await?,
+        Some(host) if host.starts_with("unix://") => {
+            let path = host.trim_start_matches("unix://");
+            DockerManager::connect(DockerEndpoint::UnixSocket(path.into())).await?
+        }
+        Some(host) => {
+            let (host, port) = parse_host_port(&host)?;
+            match (&args.tls_cert, &args.tls_key, &args.tls_ca) {
+                (Some(cert), Some(key), Some(ca)) => {
+                    DockerManager::connect(DockerEndpoint::Tls {
+                        host,
+                        port,
+                        ca: ca.into(),
+                        cert: cert.into(),
+                        key: key.into(),
+                    })
+                    .await?
+                }
+                (None, None, None) => {
+                    DockerManager::connect(DockerEndpoint::Http { host, port }).await?
+                }
+                _ => {
+                    return Err("--tls-cert, --tls-key, and --tls-ca must be given together".into());
+                }
+            }
+        }
+    };
+
+    Ok(manager)
+}
+
+/// Split a `tcp://host:port` (or bare `host:port`) address into its parts
+fn parse_host_port(host: &str) -> Result<(String, u16), Box<dyn std::error::Error>> {
+    let without_scheme = host
+        .strip_prefix("tcp://")
+        .or_else(|| host.strip_prefix("http://"))
+        .or_else(|| host.strip_prefix("https://"))
+        .unwrap_or(host);
+
+    let (hostname, port) = without_scheme
+        .rsplit_once(':')
+        .ok_or_else(|| format!("--host must be host:port, got '{host}'"))?;
 
-    Ok(())
+    Ok((hostname.to_string(), port.parse()?))
 }
@@ -2,14 +2,28 @@
 //!
 //! Handles JSON-RPC 2.0 over stdio for tool discovery and execution.
 
-use crate::docker_manager::{DockerManager, LogQuery, StartConfig};
+use crate::docker_manager::{DockerManager, LogQuery, LogStreamKind, RestartPolicy, StartConfig};
+use crate::service::{Server, Service};
 use crate::tools::{
-    DockerExecArgs, DockerLogsArgs, DockerLogsResult, DockerRunArgs, DockerRunResult,
-    DockerStopArgs, ToolResult,
+    ContainerSummary, DockerBatchArgs, DockerBatchCall, DockerBatchCallResult, DockerBatchResult,
+    DockerBuildArgs, DockerBuildResult, DockerEventRecord, DockerEventsArgs, DockerEventsResult,
+    DockerExecArgs, DockerImagesArgs, DockerImagesResult, DockerInspectArgs, DockerInspectResult,
+    DockerListArgs, DockerListResult, DockerLogsArgs, DockerLogsResult, DockerPullArgs,
+    DockerPullResult, DockerRunArgs, DockerRunResult, DockerStatsArgs, DockerStatsResult,
+    DockerStopArgs, DockerSubscribeLogsArgs, DockerSubscribeLogsResult, DockerSubscribeStatsArgs,
+    DockerSubscribeStatsResult, DockerUnsubscribeArgs, DockerUnsubscribeResult, ImageSummary,
+    NetworkIoSample, ToolResult,
 };
+use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use serde_json::{Value, json};
+use std::collections::HashMap;
 use std::io::{self, BufRead, Write};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tokio::sync::{Mutex, broadcast};
+use tokio::task::JoinHandle;
+use tokio_stream::StreamExt;
 
 /// JSON-RPC 2.0 request
 #[derive(Debug, Clone, Deserialize)]
@@ -44,6 +58,67 @@ pub struct JsonRpcError {
     pub data: Option<Value>,
 }
 
+impl JsonRpcError {
+    /// Invalid JSON was received
+    pub const PARSE_ERROR: i32 = -32700;
+    /// The JSON sent is not a valid Request object
+    pub const INVALID_REQUEST: i32 = -32600;
+    /// Invalid method parameters
+    pub const INVALID_PARAMS: i32 = -32602;
+
+    /// Application-reserved range for Docker-specific failures a caller can
+    /// branch on, distinct from the generic `isError: true` content used for
+    /// failures with no structured meaning. JSON-RPC 2.0 reserves
+    /// `-32000..=-32099` for implementation-defined server errors.
+    pub const CONTAINER_NOT_FOUND: i32 = -32000;
+    pub const IMAGE_PULL_FAILED: i32 = -32001;
+    pub const EXEC_NONZERO_EXIT: i32 = -32002;
+
+    fn new(code: i32, message: impl Into<String>, data: Option<Value>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+            data,
+        }
+    }
+
+    pub fn invalid_params(message: impl Into<String>) -> Self {
+        Self::new(Self::INVALID_PARAMS, message, None)
+    }
+
+    /// A tool referenced a container id the daemon has no record of
+    pub fn container_not_found(container_id: &str) -> Self {
+        Self::new(
+            Self::CONTAINER_NOT_FOUND,
+            format!("Container not found: {container_id}"),
+            Some(json!({ "container_id": container_id })),
+        )
+    }
+
+    /// `docker_pull` (or a `docker_build` base-image pull) failed partway
+    /// through, after the daemon had already accepted the request
+    pub fn image_pull_failed(image: &str, daemon_message: &str) -> Self {
+        Self::new(
+            Self::IMAGE_PULL_FAILED,
+            format!("Failed to pull image '{image}': {daemon_message}"),
+            Some(json!({ "image": image, "daemon_message": daemon_message })),
+        )
+    }
+
+    /// A `docker_exec` command ran to completion but exited non-zero
+    pub fn exec_nonzero_exit(container_id: &str, exit_code: i64, daemon_message: &str) -> Self {
+        Self::new(
+            Self::EXEC_NONZERO_EXIT,
+            format!("Command in container {container_id} exited with code {exit_code}"),
+            Some(json!({
+                "container_id": container_id,
+                "exit_code": exit_code,
+                "daemon_message": daemon_message,
+            })),
+        )
+    }
+}
+
 impl JsonRpcResponse {
     pub fn success(id: Option<Value>, result: Value) -> Self {
         Self {
@@ -66,6 +141,18 @@ impl JsonRpcResponse {
             }),
         }
     }
+
+    /// Like [`Self::error`], but for a pre-built [`JsonRpcError`] that carries
+    /// structured `data` a caller can branch on (e.g. a container id or exit
+    /// code), rather than just a code and a human-readable message
+    pub fn error_object(id: Option<Value>, error: JsonRpcError) -> Self {
+        Self {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(error),
+        }
+    }
 }
 
 /// MCP tool definition for tools/list response
@@ -77,10 +164,179 @@ struct McpTool {
     input_schema: Value,
 }
 
+/// Generate an MCP `inputSchema` from a tool's typed argument struct, so the
+/// schema can never drift from the struct `tools/call` actually deserializes
+/// into. `required` comes from the struct's non-`Option` fields and property
+/// descriptions come from each field's doc comment. `Option` fields keep
+/// their plain type (e.g. `"integer"`), with no `"null"` added to it.
+pub(crate) fn schema_for<T: schemars::JsonSchema>() -> Value {
+    let mut settings = schemars::gen::SchemaSettings::draft07();
+    settings.option_add_null_type = false;
+    let root = settings.into_generator().into_root_schema_for::<T>();
+    serde_json::to_value(root.schema).expect("JSON schema always serializes")
+}
+
+/// Registry of background subscription tasks (logs or stats), keyed by the
+/// monotonic subscription id handed back from `docker_subscribe_logs` or
+/// `docker_subscribe_stats`, so `docker_unsubscribe` can cancel one
+pub(crate) type SubscriptionRegistry = Arc<Mutex<HashMap<u64, JoinHandle<()>>>>;
+
+/// Shared state for the stdio transport. Request handling and background
+/// subscription tasks both write to `stdout`, so it's wrapped in a mutex to
+/// keep notification frames from interleaving with response frames.
+#[derive(Clone)]
+struct StdioState {
+    manager: DockerManager,
+    stdout: Arc<Mutex<io::Stdout>>,
+    subscriptions: SubscriptionRegistry,
+    next_subscription_id: Arc<AtomicU64>,
+}
+
+impl StdioState {
+    fn new(manager: DockerManager) -> Self {
+        Self {
+            manager,
+            stdout: Arc::new(Mutex::new(io::stdout())),
+            subscriptions: Arc::new(Mutex::new(HashMap::new())),
+            next_subscription_id: Arc::new(AtomicU64::new(1)),
+        }
+    }
+}
+
+/// Everything `dispatch_tool_call` needs from the transport it's running
+/// under: manager access, somewhere to push out-of-band notifications while
+/// a call is in flight or running in the background (progress frames,
+/// subscription pushes), and the registry `docker_unsubscribe` cancels
+/// against. Implemented by [`StdioState`] (stdout-framed notifications) and
+/// by `http_transport::HttpToolExecState` (SSE-framed notifications on the
+/// caller's session), so both transports dispatch tool calls through the
+/// same code instead of each hand-rolling their own `match tool_name`.
+pub(crate) trait ToolExecState: Send + Sync {
+    fn manager(&self) -> &DockerManager;
+    fn notification_sink(&self) -> Option<NotificationSink>;
+    fn subscriptions(&self) -> &SubscriptionRegistry;
+    fn next_subscription_id(&self) -> u64;
+}
+
+/// Where a forwarded progress frame or a subscription push lands: a framed
+/// line on the stdio transport's shared stdout handle, or an SSE event on
+/// the HTTP transport's session broadcast channel.
+#[derive(Clone)]
+pub(crate) enum NotificationSink {
+    Stdout(Arc<Mutex<io::Stdout>>),
+    Sse {
+        tx: broadcast::Sender<crate::http_transport::SseMessage>,
+        next_event_id: Arc<AtomicU64>,
+    },
+}
+
+impl NotificationSink {
+    /// Push one line of out-of-band output. Returns `false` once nothing is
+    /// listening any more (stdout closed, no SSE subscribers left), so a
+    /// background loop knows to stop pushing into the void.
+    async fn send(&self, line: String) -> bool {
+        match self {
+            NotificationSink::Stdout(stdout) => write_line(stdout, &line).await.is_ok(),
+            NotificationSink::Sse { tx, next_event_id } => {
+                let event_id = next_event_id.fetch_add(1, Ordering::Relaxed).to_string();
+                tx.send(crate::http_transport::SseMessage { event_id, data: line })
+                    .is_ok()
+            }
+        }
+    }
+}
+
+impl ToolExecState for StdioState {
+    fn manager(&self) -> &DockerManager {
+        &self.manager
+    }
+
+    fn notification_sink(&self) -> Option<NotificationSink> {
+        Some(NotificationSink::Stdout(self.stdout.clone()))
+    }
+
+    fn subscriptions(&self) -> &SubscriptionRegistry {
+        &self.subscriptions
+    }
+
+    fn next_subscription_id(&self) -> u64 {
+        self.next_subscription_id.fetch_add(1, Ordering::Relaxed)
+    }
+}
+
+/// Write one newline-delimited, flushed frame to the shared stdout handle
+async fn write_line(stdout: &Arc<Mutex<io::Stdout>>, line: &str) -> io::Result<()> {
+    let mut stdout = stdout.lock().await;
+    writeln!(stdout, "{}", line)?;
+    stdout.flush()
+}
+
+/// If the caller supplied a `progressToken` (via `tools/call` params `_meta`)
+/// and this transport/session has somewhere to push one, spawn a task that
+/// relays everything sent on the returned channel as `notifications/progress`
+/// frames tagged with that token, while the long-running tool call is still
+/// in flight. Returns `None` for both when there's no token or no sink, so
+/// the caller can skip the channel/notification overhead entirely for a
+/// plain one-shot call.
+fn spawn_progress_forwarder(
+    sink: Option<NotificationSink>,
+    progress_token: Option<Value>,
+) -> (
+    Option<tokio::sync::mpsc::UnboundedSender<String>>,
+    Option<JoinHandle<()>>,
+) {
+    let (Some(sink), Some(token)) = (sink, progress_token) else {
+        return (None, None);
+    };
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+
+    let forwarder = tokio::spawn(async move {
+        while let Some(message) = rx.recv().await {
+            let notification = json!({
+                "jsonrpc": "2.0",
+                "method": "notifications/progress",
+                "params": { "progressToken": token, "message": message },
+            })
+            .to_string();
+
+            if !sink.send(notification).await {
+                break; // nothing left to push to
+            }
+        }
+    });
+
+    (Some(tx), Some(forwarder))
+}
+
+/// Dispatches the Docker tools' JSON-RPC methods ("initialize", "tools/list",
+/// "tools/call", "ping") for the stdio transport; returns `None` for any
+/// other method so a [`Server`] chaining this with other services can fall
+/// through to the next one.
+struct DockerToolsService(StdioState);
+
+#[async_trait]
+impl Service for DockerToolsService {
+    async fn handle(&self, request: &JsonRpcRequest) -> Option<JsonRpcResponse> {
+        tracing::info!(method = %request.method, id = ?request.id, "Processing request");
+
+        match request.method.as_str() {
+            "initialize" => Some(handle_initialize(request.id.clone())),
+            "tools/list" => Some(handle_tools_list(request.id.clone())),
+            "tools/call" => Some(
+                handle_tools_call(&self.0, request.id.clone(), request.params.clone()).await,
+            ),
+            "ping" => Some(JsonRpcResponse::success(request.id.clone(), json!({}))),
+            _ => None,
+        }
+    }
+}
+
 /// Run the MCP server loop
 pub async fn run(manager: DockerManager) -> Result<(), Box<dyn std::error::Error>> {
     let stdin = io::stdin();
-    let mut stdout = io::stdout();
+    let state = StdioState::new(manager);
+    let server = Server::new(vec![Box::new(DockerToolsService(state.clone()))]);
 
     tracing::info!("MCP server ready, waiting for requests");
 
@@ -92,33 +348,86 @@ pub async fn run(manager: DockerManager) -> Result<(), Box<dyn std::error::Error
 
         tracing::info!(request = %line, "Received JSON-RPC request");
 
-        let response = match serde_json::from_str::<JsonRpcRequest>(&line) {
-            Ok(request) => handle_request(&manager, request).await,
-            Err(e) => {
-                tracing::error!(error = %e, raw_request = %line, "Failed to parse JSON-RPC request");
-                JsonRpcResponse::error(None, -32700, format!("Parse error: {}", e))
+        // The JSON-RPC 2.0 spec allows a client to send a batch of requests as
+        // a JSON array; dispatch those separately from the single-request path.
+        let output = if line.trim_start().starts_with('[') {
+            match serde_json::from_str::<Vec<JsonRpcRequest>>(&line) {
+                Ok(requests) if requests.is_empty() => {
+                    let error = JsonRpcResponse::error(
+                        None,
+                        JsonRpcError::INVALID_REQUEST,
+                        "Invalid Request: empty batch",
+                    );
+                    Some(serde_json::to_string(&error)?)
+                }
+                Ok(requests) => {
+                    let responses = handle_batch(&server, requests).await;
+                    if responses.is_empty() {
+                        None
+                    } else {
+                        Some(serde_json::to_string(&responses)?)
+                    }
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, raw_request = %line, "Failed to parse JSON-RPC batch request");
+                    let error = JsonRpcResponse::error(
+                        None,
+                        JsonRpcError::PARSE_ERROR,
+                        format!("Parse error: {}", e),
+                    );
+                    Some(serde_json::to_string(&error)?)
+                }
+            }
+        } else {
+            // A request with no `id` is a notification; per spec we must not
+            // reply to it, even though the server still runs its effects.
+            // An unparseable line can't be known to be one, so it still gets
+            // the `-32700` reply.
+            match serde_json::from_str::<JsonRpcRequest>(&line) {
+                Ok(request) if request.id.is_none() => {
+                    server.handle(&request).await;
+                    None
+                }
+                Ok(request) => {
+                    let response = server.handle(&request).await;
+                    Some(serde_json::to_string(&response)?)
+                }
+                Err(e) => {
+                    tracing::error!(error = %e, raw_request = %line, "Failed to parse JSON-RPC request");
+                    let response = JsonRpcResponse::error(
+                        None,
+                        JsonRpcError::PARSE_ERROR,
+                        format!("Parse error: {}", e),
+                    );
+                    Some(serde_json::to_string(&response)?)
+                }
             }
         };
 
-        let output = serde_json::to_string(&response)?;
-        writeln!(stdout, "{}", output)?;
-        stdout.flush()?;
+        if let Some(output) = output {
+            write_line(&state.stdout, &output).await?;
+        }
     }
 
     Ok(())
 }
 
-/// Handle a single JSON-RPC request
-async fn handle_request(manager: &DockerManager, request: JsonRpcRequest) -> JsonRpcResponse {
-    tracing::info!(method = %request.method, id = ?request.id, "Processing request");
+/// Dispatch a batch of requests concurrently and collect their responses in
+/// order. Per spec, notifications (requests with no `id`) contribute no
+/// entry to the output, so a batch of only notifications yields an empty Vec.
+async fn handle_batch(server: &Server, requests: Vec<JsonRpcRequest>) -> Vec<JsonRpcResponse> {
+    use futures::future::join_all;
 
-    match request.method.as_str() {
-        "initialize" => handle_initialize(request.id),
-        "tools/list" => handle_tools_list(request.id),
-        "tools/call" => handle_tools_call(manager, request.id, request.params).await,
-        "ping" => JsonRpcResponse::success(request.id, json!({})),
-        _ => JsonRpcResponse::error(request.id, -32601, "Method not found"),
-    }
+    let calls = requests.into_iter().map(|request| async move {
+        let is_notification = request.id.is_none();
+        (is_notification, server.handle(&request).await)
+    });
+
+    join_all(calls)
+        .await
+        .into_iter()
+        .filter_map(|(is_notification, response)| (!is_notification).then_some(response))
+        .collect()
 }
 
 /// Handle initialize request
@@ -144,88 +453,240 @@ pub fn handle_tools_list(id: Option<Value>) -> JsonRpcResponse {
         McpTool {
             name: "docker_run",
             description: "Start a long-running Docker container",
-            input_schema: json!({
-                "type": "object",
-                "properties": {
-                    "image": { "type": "string", "description": "Docker image to run" },
-                    "command": { "type": "array", "items": { "type": "string" }, "description": "Command to run" },
-                    "env_vars": { "type": "array", "items": { "type": "string" }, "description": "Environment variables (KEY=value)" },
-                    "volume_mounts": { "type": "array", "items": { "type": "string" }, "description": "Volume mounts (host:container)" },
-                    "name": { "type": "string", "description": "Container name" }
-                },
-                "required": ["image"]
-            }),
+            input_schema: schema_for::<DockerRunArgs>(),
         },
         McpTool {
             name: "docker_logs",
             description: "Fetch logs from a running container (supports incremental fetching)",
-            input_schema: json!({
-                "type": "object",
-                "properties": {
-                    "container_id": { "type": "string", "description": "Container ID" },
-                    "since": { "type": "string", "description": "ISO8601 timestamp to fetch logs since" },
-                    "tail_lines": { "type": "integer", "description": "Number of lines from end" },
-                    "stdout": { "type": "boolean", "description": "Include stdout (default: true)" },
-                    "stderr": { "type": "boolean", "description": "Include stderr (default: true)" }
-                },
-                "required": ["container_id"]
-            }),
+            input_schema: schema_for::<DockerLogsArgs>(),
         },
         McpTool {
             name: "docker_exec",
             description: "Execute a one-off command in a running container",
-            input_schema: json!({
-                "type": "object",
-                "properties": {
-                    "container_id": { "type": "string", "description": "Container ID" },
-                    "command": { "type": "string", "description": "Command to execute" }
-                },
-                "required": ["container_id", "command"]
-            }),
+            input_schema: schema_for::<DockerExecArgs>(),
         },
         McpTool {
             name: "docker_stop",
             description: "Stop and remove a container",
-            input_schema: json!({
-                "type": "object",
-                "properties": {
-                    "container_id": { "type": "string", "description": "Container ID" }
-                },
-                "required": ["container_id"]
-            }),
+            input_schema: schema_for::<DockerStopArgs>(),
         },
         McpTool {
             name: "docker_list",
-            description: "List all tracked containers",
-            input_schema: json!({
-                "type": "object",
-                "properties": {}
-            }),
+            description: "List containers known to the daemon, with optional filters",
+            input_schema: schema_for::<DockerListArgs>(),
+        },
+        McpTool {
+            name: "docker_stats",
+            description: "Sample live CPU/memory/network usage for a container",
+            input_schema: schema_for::<DockerStatsArgs>(),
+        },
+        McpTool {
+            name: "docker_inspect",
+            description: "Inspect the full state of a container",
+            input_schema: schema_for::<DockerInspectArgs>(),
+        },
+        McpTool {
+            name: "docker_pull",
+            description: "Pull an image from a registry. Pass a `progressToken` in `_meta` to receive notifications/progress frames for each layer instead of waiting for the buffered result",
+            input_schema: schema_for::<DockerPullArgs>(),
+        },
+        McpTool {
+            name: "docker_images",
+            description: "List local images",
+            input_schema: schema_for::<DockerImagesArgs>(),
+        },
+        McpTool {
+            name: "docker_build",
+            description: "Build an image from a Dockerfile and context directory. Pass a `progressToken` in `_meta` to receive notifications/progress frames for each build step instead of waiting for the buffered result",
+            input_schema: schema_for::<DockerBuildArgs>(),
+        },
+        McpTool {
+            name: "docker_events",
+            description: "Collect daemon lifecycle events (container die, oom, health_status, etc.), bounded by a count or timeout",
+            input_schema: schema_for::<DockerEventsArgs>(),
+        },
+        McpTool {
+            name: "docker_subscribe_logs",
+            description: "Tail a container's logs, pushing each line as a docker/logs JSON-RPC notification instead of a one-shot result",
+            input_schema: schema_for::<DockerSubscribeLogsArgs>(),
+        },
+        McpTool {
+            name: "docker_subscribe_stats",
+            description: "Start sampling a container's resource usage, pushing each sample as a docker/stats JSON-RPC notification",
+            input_schema: schema_for::<DockerSubscribeStatsArgs>(),
+        },
+        McpTool {
+            name: "docker_unsubscribe",
+            description: "Stop a subscription started by docker_subscribe_logs or docker_subscribe_stats",
+            input_schema: schema_for::<DockerUnsubscribeArgs>(),
+        },
+        McpTool {
+            name: "docker_batch",
+            description: "Execute a batch of tool calls across a bounded worker pool, returning one result per call in input order",
+            input_schema: schema_for::<DockerBatchArgs>(),
         },
     ];
 
     JsonRpcResponse::success(id, json!({ "tools": tools }))
 }
 
-/// Handle tools/call request
-async fn handle_tools_call(
-    manager: &DockerManager,
+/// Outcome of dispatching a `tools/call`: a typed-argument mismatch is a
+/// malformed request (reported as a protocol-level `-32602` error); a daemon
+/// failure a caller could reasonably branch on (container id, exit code) is
+/// reported as a structured, code-bearing protocol-level error too, but only
+/// by `docker_exec`, `docker_stats` and `docker_pull`; anything else -
+/// including a missing container in `docker_stop`, `docker_logs` or
+/// `docker_inspect` - is a tool-level failure (reported as `isError: true`
+/// content, per the MCP spec)
+pub(crate) enum ToolError {
+    InvalidParams(String),
+    Rpc(JsonRpcError),
+    Execution(String),
+}
+
+/// Classify a Docker daemon failure as a structured [`JsonRpcError`] a caller
+/// can branch on, or fall back to an opaque tool-level execution failure.
+/// `pub(crate)` so `http_transport` can give HTTP/SSE clients the same
+/// structured errors the stdio transport does for the calls it's wired up.
+pub(crate) fn classify_docker_error(e: crate::docker_manager::DockerError) -> ToolError {
+    use crate::docker_manager::DockerError;
+    match e {
+        DockerError::ContainerNotFound(id) => ToolError::Rpc(JsonRpcError::container_not_found(&id)),
+        DockerError::ExecNonZeroExit {
+            container_id,
+            exit_code,
+            message,
+        } => ToolError::Rpc(JsonRpcError::exec_nonzero_exit(&container_id, exit_code, &message)),
+        other => ToolError::Execution(other.to_string()),
+    }
+}
+
+/// Deserialize `arguments` into a tool's typed argument struct, mapping a
+/// mismatch to `ToolError::InvalidParams` instead of letting it read as a
+/// generic execution failure
+fn parse_tool_args<T: serde::de::DeserializeOwned>(arguments: Value) -> Result<T, ToolError> {
+    serde_json::from_value(arguments).map_err(|e| ToolError::InvalidParams(e.to_string()))
+}
+
+/// Dispatch a single named tool call against `state`, parsing `arguments`
+/// into that tool's typed argument struct first. Shared between top-level
+/// `tools/call` handling and `docker_batch`'s per-sub-call dispatch, so a
+/// sub-call sees exactly the same validation and error classification a
+/// top-level call would.
+///
+/// `docker_batch` dispatches back into this function for each of its
+/// sub-calls, so the return type is boxed (`BoxFuture` rather than a plain
+/// `async fn`) to break that recursive-async cycle — otherwise rustc can't
+/// size the self-referential future (E0733).
+fn dispatch_tool_call<'a>(
+    state: &'a dyn ToolExecState,
+    tool_name: &'a str,
+    arguments: Value,
+    progress_token: Option<Value>,
+) -> futures::future::BoxFuture<'a, Result<String, ToolError>> {
+    Box::pin(dispatch_tool_call_inner(state, tool_name, arguments, progress_token))
+}
+
+async fn dispatch_tool_call_inner(
+    state: &dyn ToolExecState,
+    tool_name: &str,
+    arguments: Value,
+    progress_token: Option<Value>,
+) -> Result<String, ToolError> {
+    let manager = state.manager();
+
+    match tool_name {
+        "docker_run" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_run(manager, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_logs" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_logs(manager, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_exec" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_exec(manager, args).await,
+            Err(e) => Err(e),
+        },
+        "docker_stop" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_stop(manager, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_list" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_list(manager, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_stats" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_stats(manager, args).await,
+            Err(e) => Err(e),
+        },
+        "docker_inspect" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_inspect(manager, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_pull" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_pull(state, args, progress_token).await,
+            Err(e) => Err(e),
+        },
+        "docker_images" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_images(manager, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_build" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_build(state, args, progress_token)
+                .await
+                .map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_events" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_events(manager, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_subscribe_logs" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_subscribe_logs(state, args)
+                .await
+                .map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_subscribe_stats" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_subscribe_stats(state, args)
+                .await
+                .map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_unsubscribe" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_unsubscribe(state, args)
+                .await
+                .map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        "docker_batch" => match parse_tool_args(arguments) {
+            Ok(args) => handle_docker_batch(state, args).await.map_err(ToolError::Execution),
+            Err(e) => Err(e),
+        },
+        _ => Err(ToolError::Execution(format!("Unknown tool: {}", tool_name))),
+    }
+}
+
+/// Handle a `tools/call` request by running it through [`dispatch_tool_call`]
+/// and shaping the result into the MCP response envelope. `pub(crate)` so
+/// `http_transport` dispatches through this same chokepoint instead of
+/// hand-rolling its own `match tool_name` over the manager.
+pub(crate) async fn handle_tools_call(
+    state: &dyn ToolExecState,
     id: Option<Value>,
     params: Value,
 ) -> JsonRpcResponse {
     let tool_name = params.get("name").and_then(|v| v.as_str()).unwrap_or("");
     let arguments = params.get("arguments").cloned().unwrap_or(json!({}));
+    let progress_token = params
+        .get("_meta")
+        .and_then(|meta| meta.get("progressToken"))
+        .cloned();
 
     tracing::info!(tool = %tool_name, arguments = %arguments, "Executing tool");
 
-    let result = match tool_name {
-        "docker_run" => handle_docker_run(manager, arguments).await,
-        "docker_logs" => handle_docker_logs(manager, arguments).await,
-        "docker_exec" => handle_docker_exec(manager, arguments).await,
-        "docker_stop" => handle_docker_stop(manager, arguments).await,
-        "docker_list" => handle_docker_list(manager).await,
-        _ => Err(format!("Unknown tool: {}", tool_name)),
-    };
+    let result = dispatch_tool_call(state, tool_name, arguments, progress_token).await;
 
     match result {
         Ok(content) => {
@@ -240,7 +701,15 @@ async fn handle_tools_call(
                 }),
             )
         }
-        Err(e) => {
+        Err(ToolError::InvalidParams(e)) => {
+            tracing::warn!(tool = %tool_name, error = %e, "Rejected tool call with invalid params");
+            JsonRpcResponse::error_object(id, JsonRpcError::invalid_params(format!("Invalid params: {e}")))
+        }
+        Err(ToolError::Rpc(e)) => {
+            tracing::warn!(tool = %tool_name, code = e.code, error = %e.message, "Tool call failed with a structured Docker error");
+            JsonRpcResponse::error_object(id, e)
+        }
+        Err(ToolError::Execution(e)) => {
             tracing::error!(tool = %tool_name, error = %e, "Tool execution failed");
             JsonRpcResponse::success(
                 id,
@@ -256,26 +725,26 @@ async fn handle_tools_call(
     }
 }
 
-async fn handle_docker_run(manager: &DockerManager, args: Value) -> Result<String, String> {
-    let args: DockerRunArgs = serde_json::from_value(args).map_err(|e| e.to_string())?;
-
+async fn handle_docker_run(manager: &DockerManager, args: DockerRunArgs) -> Result<String, String> {
     let config = StartConfig {
         image: args.image,
         command: args.command,
         env_vars: args.env_vars,
-        volume_mounts: args
-            .volume_mounts
-            .iter()
-            .filter_map(|m| {
-                let parts: Vec<&str> = m.splitn(2, ':').collect();
-                if parts.len() == 2 {
-                    Some((parts[0].to_string(), parts[1].to_string()))
-                } else {
-                    None
-                }
-            })
-            .collect(),
+        volume_mounts: args.volume_mounts,
         name: args.name,
+        ports: args.ports,
+        memory_bytes: args.memory_mb.map(|mb| mb * 1024 * 1024),
+        memory_swap_bytes: args
+            .memory_swap_mb
+            .map(|mb| if mb < 0 { -1 } else { mb.saturating_mul(1024 * 1024) }),
+        nano_cpus: args.cpus.map(|cpus| (cpus * 1_000_000_000.0) as i64),
+        cpu_shares: args.cpu_shares,
+        restart: args
+            .restart
+            .map(|r| r.parse::<RestartPolicy>())
+            .transpose()
+            .map_err(|e| e.to_string())?,
+        labels: args.labels,
     };
 
     let container_id = manager
@@ -292,9 +761,10 @@ async fn handle_docker_run(manager: &DockerManager, args: Value) -> Result<Strin
     serde_json::to_string(&result).map_err(|e| e.to_string())
 }
 
-async fn handle_docker_logs(manager: &DockerManager, args: Value) -> Result<String, String> {
-    let args: DockerLogsArgs = serde_json::from_value(args).map_err(|e| e.to_string())?;
-
+async fn handle_docker_logs(
+    manager: &DockerManager,
+    args: DockerLogsArgs,
+) -> Result<String, String> {
     let query = LogQuery {
         container_id: args.container_id,
         since: args.since.and_then(|s| {
@@ -303,6 +773,7 @@ async fn handle_docker_logs(manager: &DockerManager, args: Value) -> Result<Stri
         tail_lines: args.tail_lines,
         include_stdout: args.stdout.unwrap_or(true),
         include_stderr: args.stderr.unwrap_or(true),
+        follow: args.follow.unwrap_or(false),
     };
 
     let logs = manager.get_logs(query).await.map_err(|e| e.to_string())?;
@@ -320,13 +791,11 @@ async fn handle_docker_logs(manager: &DockerManager, args: Value) -> Result<Stri
     serde_json::to_string(&result).map_err(|e| e.to_string())
 }
 
-async fn handle_docker_exec(manager: &DockerManager, args: Value) -> Result<String, String> {
-    let args: DockerExecArgs = serde_json::from_value(args).map_err(|e| e.to_string())?;
-
+async fn handle_docker_exec(manager: &DockerManager, args: DockerExecArgs) -> Result<String, ToolError> {
     let output = manager
         .exec_command(&args.container_id, &args.command)
         .await
-        .map_err(|e| e.to_string())?;
+        .map_err(classify_docker_error)?;
 
     let result = ToolResult {
         success: true,
@@ -334,12 +803,13 @@ async fn handle_docker_exec(manager: &DockerManager, args: Value) -> Result<Stri
         error: None,
     };
 
-    serde_json::to_string(&result).map_err(|e| e.to_string())
+    serde_json::to_string(&result).map_err(|e| ToolError::Execution(e.to_string()))
 }
 
-async fn handle_docker_stop(manager: &DockerManager, args: Value) -> Result<String, String> {
-    let args: DockerStopArgs = serde_json::from_value(args).map_err(|e| e.to_string())?;
-
+async fn handle_docker_stop(
+    manager: &DockerManager,
+    args: DockerStopArgs,
+) -> Result<String, String> {
     manager
         .stop_container(&args.container_id)
         .await
@@ -354,28 +824,392 @@ async fn handle_docker_stop(manager: &DockerManager, args: Value) -> Result<Stri
     serde_json::to_string(&result).map_err(|e| e.to_string())
 }
 
-async fn handle_docker_list(manager: &DockerManager) -> Result<String, String> {
-    let containers = manager.list_containers().await;
-
-    let list: Vec<Value> = containers
-        .iter()
-        .map(|c| {
-            json!({
-                "id": c.id,
-                "name": c.name,
-                "image": c.image,
-                "started_at": c.started_at.format(&time::format_description::well_known::Rfc3339).unwrap_or_default(),
-                "status": match &c.status {
-                    crate::docker_manager::ContainerStatus::Running => "running",
-                    crate::docker_manager::ContainerStatus::Stopped => "stopped",
-                    crate::docker_manager::ContainerStatus::Exited(_) => "exited",
-                    crate::docker_manager::ContainerStatus::Error(_) => "error",
-                }
+async fn handle_docker_list(
+    manager: &DockerManager,
+    args: DockerListArgs,
+) -> Result<String, String> {
+    let containers = manager
+        .list_containers_from_daemon(args.all.unwrap_or(false), args.filters)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let result = DockerListResult {
+        success: true,
+        containers: containers
+            .into_iter()
+            .map(|c| ContainerSummary {
+                id: c.id,
+                names: c.names,
+                image: c.image,
+                state: c.state,
+                status: c.status,
+                ports: c.ports,
             })
+            .collect(),
+    };
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+async fn handle_docker_stats(manager: &DockerManager, args: DockerStatsArgs) -> Result<String, ToolError> {
+    if args.stream.unwrap_or(false) {
+        return Err(ToolError::Execution(
+            "docker_stats with stream=true requires the HTTP transport's SSE session".to_string(),
+        ));
+    }
+
+    let sample = manager
+        .get_stats(&args.container_id)
+        .await
+        .map_err(classify_docker_error)?;
+
+    let result = DockerStatsResult {
+        success: true,
+        container_id: args.container_id,
+        cpu_percent: sample.cpu_percent,
+        mem_usage_bytes: sample.mem_usage_bytes,
+        mem_limit_bytes: sample.mem_limit_bytes,
+        mem_percent: sample.mem_percent,
+        networks: sample
+            .networks
+            .into_iter()
+            .map(|(iface, (rx_bytes, tx_bytes))| (iface, NetworkIoSample { rx_bytes, tx_bytes }))
+            .collect(),
+    };
+
+    serde_json::to_string(&result).map_err(|e| ToolError::Execution(e.to_string()))
+}
+
+async fn handle_docker_inspect(
+    manager: &DockerManager,
+    args: DockerInspectArgs,
+) -> Result<String, String> {
+    let details = manager
+        .inspect(&args.container_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let result = DockerInspectResult {
+        success: true,
+        container_id: args.container_id,
+        status: details.status,
+        running: details.running,
+        exit_code: details.exit_code,
+        started_at: details.started_at,
+        finished_at: details.finished_at,
+        oom_killed: details.oom_killed,
+        restart_count: details.restart_count,
+        image: details.image,
+        ip_address: details.ip_address,
+        ports: details.ports,
+        mounts: details.mounts,
+        command: details.command,
+        env: details.env,
+    };
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+async fn handle_docker_pull(
+    state: &dyn ToolExecState,
+    args: DockerPullArgs,
+    progress_token: Option<Value>,
+) -> Result<String, ToolError> {
+    let (tx, forwarder) = spawn_progress_forwarder(state.notification_sink(), progress_token);
+    let pulled = state
+        .manager()
+        .pull_image_with_progress(
+            &args.image,
+            args.tag.as_deref(),
+            args.registry_auth.as_deref(),
+            tx.as_ref(),
+        )
+        .await;
+    drop(tx);
+    if let Some(forwarder) = forwarder {
+        let _ = forwarder.await;
+    }
+    let (digest, progress) = pulled
+        .map_err(|e| ToolError::Rpc(JsonRpcError::image_pull_failed(&args.image, &e.to_string())))?;
+
+    let result = DockerPullResult {
+        success: true,
+        image: args.image,
+        digest,
+        progress,
+    };
+
+    serde_json::to_string(&result).map_err(|e| ToolError::Execution(e.to_string()))
+}
+
+async fn handle_docker_images(
+    manager: &DockerManager,
+    args: DockerImagesArgs,
+) -> Result<String, String> {
+    let images = manager
+        .list_images(args.filter.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let result = DockerImagesResult {
+        success: true,
+        images: images
+            .into_iter()
+            .map(|img| ImageSummary {
+                repo_tags: img.repo_tags,
+                id: img.id,
+                size_bytes: img.size_bytes,
+                created: img.created,
+            })
+            .collect(),
+    };
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+async fn handle_docker_build(
+    state: &dyn ToolExecState,
+    args: DockerBuildArgs,
+    progress_token: Option<Value>,
+) -> Result<String, String> {
+    let dockerfile = args.dockerfile.as_deref().unwrap_or("Dockerfile");
+    let (tx, forwarder) = spawn_progress_forwarder(state.notification_sink(), progress_token);
+    let built = state
+        .manager()
+        .build_image_with_progress(
+            std::path::Path::new(&args.context_path),
+            dockerfile,
+            args.tag.as_deref(),
+            &args.build_args,
+            args.nocache.unwrap_or(false),
+            tx.as_ref(),
+        )
+        .await;
+    drop(tx);
+    if let Some(forwarder) = forwarder {
+        let _ = forwarder.await;
+    }
+    let (image_id, output) = built.map_err(|e| e.to_string())?;
+
+    let result = DockerBuildResult {
+        success: true,
+        image_id,
+        output,
+    };
+
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+async fn handle_docker_events(
+    manager: &DockerManager,
+    args: DockerEventsArgs,
+) -> Result<String, String> {
+    if args.stream.unwrap_or(false) {
+        return Err(
+            "docker_events with stream=true requires the HTTP transport's SSE session"
+                .to_string(),
+        );
+    }
+
+    let parse_time = |s: Option<String>| {
+        s.and_then(|s| {
+            time::OffsetDateTime::parse(&s, &time::format_description::well_known::Rfc3339).ok()
         })
-        .collect();
+    };
+
+    let events = manager
+        .collect_events(
+            parse_time(args.since),
+            parse_time(args.until),
+            args.filters,
+            args.count,
+            args.timeout_secs.unwrap_or(30),
+        )
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let result = DockerEventsResult {
+        success: true,
+        events: events
+            .into_iter()
+            .map(|e| DockerEventRecord {
+                event_type: e.event_type,
+                action: e.action,
+                actor_id: e.actor_id,
+                attributes: e.attributes,
+                time: e.time,
+            })
+            .collect(),
+    };
 
-    serde_json::to_string(&json!({ "containers": list })).map_err(|e| e.to_string())
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+/// Start tailing a container's logs in the background, pushing each line as
+/// a `docker/logs` notification tagged with the subscription id, over
+/// whichever sink this transport/session has (stdout for stdio, SSE for
+/// HTTP)
+async fn handle_docker_subscribe_logs(
+    state: &dyn ToolExecState,
+    args: DockerSubscribeLogsArgs,
+) -> Result<String, String> {
+    let Some(sink) = state.notification_sink() else {
+        return Err("no notification channel available to push subscription updates on".to_string());
+    };
+    let subscription_id = state.next_subscription_id();
+    let manager = state.manager().clone();
+    let container_id = args.container_id;
+
+    let task = tokio::spawn(async move {
+        let mut stream = manager.stream_logs(&container_id);
+
+        while let Some(chunk) = stream.next().await {
+            let params = match chunk.stream {
+                LogStreamKind::Stdout => {
+                    json!({ "subscription": subscription_id, "stdout": chunk.text })
+                }
+                LogStreamKind::Stderr => {
+                    json!({ "subscription": subscription_id, "stderr": chunk.text })
+                }
+            };
+            let notification = json!({
+                "jsonrpc": "2.0",
+                "method": "docker/logs",
+                "params": params,
+            })
+            .to_string();
+
+            if !sink.send(notification).await {
+                break; // nothing left to push to
+            }
+        }
+    });
+
+    state.subscriptions().lock().await.insert(subscription_id, task);
+
+    let result = DockerSubscribeLogsResult {
+        success: true,
+        subscription: subscription_id,
+    };
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+/// Start sampling a container's resource usage in the background, pushing
+/// each sample as a `docker/stats` notification tagged with the subscription
+/// id, over whichever sink this transport/session has (stdout for stdio, SSE
+/// for HTTP)
+async fn handle_docker_subscribe_stats(
+    state: &dyn ToolExecState,
+    args: DockerSubscribeStatsArgs,
+) -> Result<String, String> {
+    let Some(sink) = state.notification_sink() else {
+        return Err("no notification channel available to push subscription updates on".to_string());
+    };
+    let subscription_id = state.next_subscription_id();
+    let manager = state.manager().clone();
+    let container_id = args.container_id;
+
+    let task = tokio::spawn(async move {
+        let mut stream = manager.stream_stats(&container_id);
+
+        while let Some(sample) = stream.next().await {
+            let result = DockerStatsResult {
+                success: true,
+                container_id: container_id.clone(),
+                cpu_percent: sample.cpu_percent,
+                mem_usage_bytes: sample.mem_usage_bytes,
+                mem_limit_bytes: sample.mem_limit_bytes,
+                mem_percent: sample.mem_percent,
+                networks: sample
+                    .networks
+                    .into_iter()
+                    .map(|(iface, (rx_bytes, tx_bytes))| {
+                        (iface, NetworkIoSample { rx_bytes, tx_bytes })
+                    })
+                    .collect(),
+            };
+
+            let notification = json!({
+                "jsonrpc": "2.0",
+                "method": "docker/stats",
+                "params": { "subscription": subscription_id, "stats": result },
+            })
+            .to_string();
+
+            if !sink.send(notification).await {
+                break; // nothing left to push to
+            }
+        }
+    });
+
+    state.subscriptions().lock().await.insert(subscription_id, task);
+
+    let result = DockerSubscribeStatsResult {
+        success: true,
+        subscription: subscription_id,
+    };
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+/// Cancel a subscription started by `docker_subscribe_logs` or `docker_subscribe_stats`
+async fn handle_docker_unsubscribe(
+    state: &dyn ToolExecState,
+    args: DockerUnsubscribeArgs,
+) -> Result<String, String> {
+    let task = state.subscriptions().lock().await.remove(&args.subscription);
+    let success = task.is_some();
+    if let Some(task) = task {
+        task.abort();
+    }
+
+    let result = DockerUnsubscribeResult { success };
+    serde_json::to_string(&result).map_err(|e| e.to_string())
+}
+
+/// Execute a batch of sub-calls, each dispatched the same way a top-level
+/// `tools/call` would be. Mirrors `handle_batch`'s join_all-based fan-out,
+/// which already preserves input order regardless of completion order, but
+/// additionally bounds concurrency with a semaphore sized to the host's
+/// available parallelism, so a large batch can't exhaust the Docker daemon's
+/// connection pool. One sub-call failing (bad arguments or a daemon error)
+/// is captured in its own result and doesn't stop the others.
+async fn handle_docker_batch(state: &dyn ToolExecState, args: DockerBatchArgs) -> Result<String, String> {
+    use futures::future::join_all;
+    use tokio::sync::Semaphore;
+
+    let permits = std::thread::available_parallelism().map(|n| n.get()).unwrap_or(4);
+    let semaphore = Arc::new(Semaphore::new(permits));
+
+    let calls = args.calls.into_iter().map(|call| {
+        let semaphore = semaphore.clone();
+        async move {
+            let _permit = semaphore.acquire_owned().await.expect("semaphore is never closed");
+            match dispatch_tool_call(state, &call.name, call.arguments, None).await {
+                Ok(output) => DockerBatchCallResult {
+                    name: call.name,
+                    success: true,
+                    output,
+                    error: None,
+                },
+                Err(e) => DockerBatchCallResult {
+                    name: call.name,
+                    success: false,
+                    output: String::new(),
+                    error: Some(match e {
+                        ToolError::InvalidParams(msg) => msg,
+                        ToolError::Rpc(err) => err.message,
+                        ToolError::Execution(msg) => msg,
+                    }),
+                },
+            }
+        }
+    });
+
+    let results = join_all(calls).await;
+    let success = results.iter().all(|r| r.success);
+
+    let result = DockerBatchResult { success, results };
+    serde_json::to_string(&result).map_err(|e| e.to_string())
 }
 
 #[cfg(test)]
@@ -434,6 +1268,25 @@ mod tests {
         assert_eq!(request.method, "tools/list");
     }
 
+    #[test]
+    fn test_json_rpc_batch_parse() {
+        let json_str = r#"[
+            {"jsonrpc":"2.0","id":1,"method":"ping"},
+            {"jsonrpc":"2.0","id":2,"method":"tools/list"}
+        ]"#;
+        let requests: Vec<JsonRpcRequest> = serde_json::from_str(json_str).unwrap();
+
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "ping");
+        assert_eq!(requests[1].method, "tools/list");
+    }
+
+    #[test]
+    fn test_json_rpc_batch_parse_empty() {
+        let requests: Vec<JsonRpcRequest> = serde_json::from_str("[]").unwrap();
+        assert!(requests.is_empty());
+    }
+
     #[test]
     fn test_handle_initialize() {
         let response = handle_initialize(Some(json!(1)));
@@ -467,8 +1320,8 @@ mod tests {
         let result = response.result.unwrap();
         let tools = result["tools"].as_array().unwrap();
 
-        // Should have 5 tools
-        assert_eq!(tools.len(), 5);
+        // Should have 15 tools
+        assert_eq!(tools.len(), 15);
 
         // Check tool names
         let tool_names: Vec<&str> = tools.iter().map(|t| t["name"].as_str().unwrap()).collect();
@@ -477,6 +1330,16 @@ mod tests {
         assert!(tool_names.contains(&"docker_exec"));
         assert!(tool_names.contains(&"docker_stop"));
         assert!(tool_names.contains(&"docker_list"));
+        assert!(tool_names.contains(&"docker_stats"));
+        assert!(tool_names.contains(&"docker_inspect"));
+        assert!(tool_names.contains(&"docker_pull"));
+        assert!(tool_names.contains(&"docker_images"));
+        assert!(tool_names.contains(&"docker_build"));
+        assert!(tool_names.contains(&"docker_events"));
+        assert!(tool_names.contains(&"docker_subscribe_logs"));
+        assert!(tool_names.contains(&"docker_subscribe_stats"));
+        assert!(tool_names.contains(&"docker_unsubscribe"));
+        assert!(tool_names.contains(&"docker_batch"));
     }
 
     #[test]
@@ -498,6 +1361,165 @@ mod tests {
         assert_eq!(schema["required"], json!(["image"]));
     }
 
+    #[test]
+    fn test_schema_for_docker_run_args() {
+        let schema = schema_for::<DockerRunArgs>();
+
+        assert_eq!(schema["required"], json!(["image"]));
+        let props = &schema["properties"];
+        assert!(props.get("image").is_some());
+        assert!(props.get("command").is_some());
+        assert!(props.get("env_vars").is_some());
+        assert!(props.get("volume_mounts").is_some());
+        assert!(props.get("name").is_some());
+    }
+
+    #[test]
+    fn test_schema_for_docker_logs_args() {
+        let schema = schema_for::<DockerLogsArgs>();
+
+        assert_eq!(schema["required"], json!(["container_id"]));
+        assert_eq!(schema["properties"]["tail_lines"]["type"], "integer");
+    }
+
+    #[test]
+    fn test_schema_for_docker_exec_args() {
+        let schema = schema_for::<DockerExecArgs>();
+
+        let required = schema["required"].as_array().unwrap();
+        assert!(required.contains(&json!("container_id")));
+        assert!(required.contains(&json!("command")));
+    }
+
+    #[test]
+    fn test_schema_for_docker_stop_args() {
+        let schema = schema_for::<DockerStopArgs>();
+        assert_eq!(schema["required"], json!(["container_id"]));
+    }
+
+    #[test]
+    fn test_schema_for_docker_list_args_has_all_and_filters() {
+        let schema = schema_for::<DockerListArgs>();
+        assert!(schema["properties"].get("all").is_some());
+        assert!(schema["properties"].get("filters").is_some());
+    }
+
+    #[test]
+    fn test_schema_for_docker_build_args_requires_context_path() {
+        let schema = schema_for::<DockerBuildArgs>();
+
+        assert_eq!(schema["required"], json!(["context_path"]));
+        assert!(schema["properties"].get("nocache").is_some());
+    }
+
+    #[test]
+    fn test_schema_for_docker_pull_args_requires_image() {
+        let schema = schema_for::<DockerPullArgs>();
+
+        assert_eq!(schema["required"], json!(["image"]));
+        assert!(schema["properties"].get("registry_auth").is_some());
+    }
+
+    #[test]
+    fn test_schema_for_derives_required_from_non_optional_fields() {
+        let schema = schema_for::<DockerExecArgs>();
+
+        assert_eq!(schema["required"], json!(["command", "container_id"]));
+        assert!(schema["properties"]["container_id"].is_object());
+    }
+
+    #[test]
+    fn test_parse_tool_args_rejects_missing_required_field() {
+        let err = parse_tool_args::<DockerRunArgs>(json!({})).unwrap_err();
+
+        match err {
+            ToolError::InvalidParams(_) => {}
+            ToolError::Rpc(e) => panic!("expected InvalidParams, got Rpc({e:?})"),
+            ToolError::Execution(e) => panic!("expected InvalidParams, got Execution({e})"),
+        }
+    }
+
+    #[test]
+    fn test_container_not_found_uses_reserved_range_and_carries_container_id() {
+        let error = JsonRpcError::container_not_found("abc123");
+
+        assert_eq!(error.code, JsonRpcError::CONTAINER_NOT_FOUND);
+        assert!((-32099..=-32000).contains(&error.code));
+        assert_eq!(error.data.unwrap()["container_id"], "abc123");
+    }
+
+    #[test]
+    fn test_exec_nonzero_exit_carries_exit_code_in_data() {
+        let error = JsonRpcError::exec_nonzero_exit("abc123", 127, "sh: not-a-command: not found");
+
+        assert_eq!(error.code, JsonRpcError::EXEC_NONZERO_EXIT);
+        let data = error.data.unwrap();
+        assert_eq!(data["container_id"], "abc123");
+        assert_eq!(data["exit_code"], 127);
+    }
+
+    #[test]
+    fn test_classify_docker_error_maps_container_not_found_to_rpc_error() {
+        use crate::docker_manager::DockerError;
+
+        let err = classify_docker_error(DockerError::ContainerNotFound("abc123".to_string()));
+
+        match err {
+            ToolError::Rpc(e) => {
+                assert_eq!(e.code, JsonRpcError::CONTAINER_NOT_FOUND);
+                assert_eq!(e.data.unwrap()["container_id"], "abc123");
+            }
+            ToolError::Execution(e) => panic!("expected ToolError::Rpc, got Execution({e})"),
+            ToolError::InvalidParams(e) => panic!("expected ToolError::Rpc, got InvalidParams({e})"),
+        }
+    }
+
+    #[test]
+    fn test_classify_docker_error_maps_exec_nonzero_exit_to_rpc_error_with_exit_code() {
+        use crate::docker_manager::DockerError;
+
+        let err = classify_docker_error(DockerError::ExecNonZeroExit {
+            container_id: "abc123".to_string(),
+            exit_code: 2,
+            message: "oops".to_string(),
+        });
+
+        match err {
+            ToolError::Rpc(e) => {
+                assert_eq!(e.code, JsonRpcError::EXEC_NONZERO_EXIT);
+                assert_eq!(e.data.as_ref().unwrap()["exit_code"], 2);
+                assert_eq!(e.data.unwrap()["daemon_message"], "oops");
+            }
+            ToolError::Execution(e) => panic!("expected ToolError::Rpc, got Execution({e})"),
+            ToolError::InvalidParams(e) => panic!("expected ToolError::Rpc, got InvalidParams({e})"),
+        }
+    }
+
+    #[test]
+    fn test_classify_docker_error_falls_back_to_execution_for_other_variants() {
+        use crate::docker_manager::DockerError;
+
+        let err = classify_docker_error(DockerError::InvalidConfig("bad port spec".to_string()));
+
+        match err {
+            ToolError::Execution(e) => assert!(e.contains("bad port spec")),
+            ToolError::Rpc(e) => panic!("expected ToolError::Execution, got Rpc({e:?})"),
+            ToolError::InvalidParams(e) => panic!("expected ToolError::Execution, got InvalidParams({e})"),
+        }
+    }
+
+    #[test]
+    fn test_error_object_preserves_structured_data() {
+        let error = JsonRpcError::container_not_found("abc123");
+        let response = JsonRpcResponse::error_object(Some(json!(1)), error);
+
+        let json_str = serde_json::to_string(&response).unwrap();
+        let parsed: Value = serde_json::from_str(&json_str).unwrap();
+        assert_eq!(parsed["error"]["code"], JsonRpcError::CONTAINER_NOT_FOUND);
+        assert_eq!(parsed["error"]["data"]["container_id"], "abc123");
+        assert!(parsed.get("result").is_none());
+    }
+
     #[test]
     fn test_json_rpc_response_serialization() {
         let response = JsonRpcResponse::success(Some(json!(1)), json!({"status": "ok"}));
@@ -536,4 +1558,139 @@ mod tests {
         let result = serde_json::from_str::<JsonRpcRequest>(r#"{"jsonrpc":"2.0","id":1}"#);
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_handle_docker_batch_preserves_order_with_mixed_outcomes() {
+        // `DockerManager::new` only builds a client for the local socket; it
+        // doesn't dial the daemon, so this doesn't need Docker available.
+        let manager = DockerManager::new().await.expect("building a client doesn't require a reachable daemon");
+        let state = StdioState::new(manager);
+
+        // These resolve through very different paths -- an unknown tool
+        // name fails synchronously with no `.await` at all, a missing
+        // required field fails in `parse_tool_args` before ever touching
+        // the manager, and `docker_list` actually awaits the (unreachable)
+        // daemon -- so their completion order is unlikely to match the
+        // order they were submitted in. Results must come back in input
+        // order regardless.
+        let args = DockerBatchArgs {
+            calls: vec![
+                DockerBatchCall {
+                    name: "docker_list".to_string(),
+                    arguments: json!({}),
+                },
+                DockerBatchCall {
+                    name: "does_not_exist".to_string(),
+                    arguments: json!({}),
+                },
+                DockerBatchCall {
+                    name: "docker_run".to_string(),
+                    arguments: json!({}), // missing required `image` field
+                },
+            ],
+        };
+
+        let output = handle_docker_batch(&state, args).await.unwrap();
+        let result: DockerBatchResult = serde_json::from_str(&output).unwrap();
+
+        assert_eq!(result.results.len(), 3);
+        assert_eq!(result.results[0].name, "docker_list");
+        assert_eq!(result.results[1].name, "does_not_exist");
+        assert_eq!(result.results[2].name, "docker_run");
+
+        assert!(!result.success);
+        assert!(!result.results[1].success);
+        assert!(result.results[1].error.is_some());
+        assert!(!result.results[2].success);
+        assert!(result.results[2].error.is_some());
+    }
+
+    /// Build a `Server` wired up the same way `run()` does, without needing a
+    /// reachable Docker daemon.
+    async fn test_server() -> Server {
+        let manager = DockerManager::new().await.expect("building a client doesn't require a reachable daemon");
+        Server::new(vec![Box::new(DockerToolsService(StdioState::new(manager)))])
+    }
+
+    fn request(id: Option<Value>, method: &str) -> JsonRpcRequest {
+        JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id,
+            method: method.to_string(),
+            params: json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_batch_preserves_ids_and_skips_notifications() {
+        let server = test_server().await;
+        let requests = vec![
+            request(Some(json!(1)), "ping"),
+            request(None, "ping"), // notification, no id
+            request(Some(json!(2)), "unknown"),
+        ];
+
+        let responses = handle_batch(&server, requests).await;
+
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0].id, Some(json!(1)));
+        assert!(responses[0].result.is_some());
+        assert_eq!(responses[1].id, Some(json!(2)));
+        assert_eq!(responses[1].error.as_ref().unwrap().code, -32601);
+    }
+
+    #[tokio::test]
+    async fn test_handle_batch_of_only_notifications_yields_no_response() {
+        let server = test_server().await;
+        let requests = vec![request(None, "ping"), request(None, "ping")];
+
+        assert!(handle_batch(&server, requests).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_batch_one_failing_sub_call_does_not_abort_others() {
+        let server = test_server().await;
+        let requests = vec![
+            request(Some(json!(1)), "ping"),
+            request(Some(json!(2)), "does_not_exist"),
+            request(Some(json!(3)), "ping"),
+        ];
+
+        let responses = handle_batch(&server, requests).await;
+
+        assert_eq!(responses.len(), 3);
+        assert!(responses[0].result.is_some());
+        assert!(responses[1].error.is_some());
+        assert!(responses[2].result.is_some());
+    }
+
+    #[test]
+    fn test_json_rpc_batch_parse_malformed_is_parse_error() {
+        let malformed = "[{\"jsonrpc\": \"2.0\", \"id\": 1,]";
+        let parsed: Result<Vec<JsonRpcRequest>, _> = serde_json::from_str(malformed);
+        assert!(parsed.is_err());
+    }
+
+    #[test]
+    fn test_parse_error_constant_matches_json_rpc_spec() {
+        assert_eq!(JsonRpcError::PARSE_ERROR, -32700);
+    }
+
+    #[test]
+    fn test_invalid_request_constant_matches_json_rpc_spec() {
+        assert_eq!(JsonRpcError::INVALID_REQUEST, -32600);
+    }
+
+    #[test]
+    fn test_invalid_params_constant_matches_json_rpc_spec() {
+        assert_eq!(JsonRpcError::INVALID_PARAMS, -32602);
+    }
+
+    #[tokio::test]
+    async fn test_unrecognized_method_falls_back_to_method_not_found() {
+        let server = test_server().await;
+        let response = server.handle(&request(Some(json!(1)), "no_such_method")).await;
+
+        assert_eq!(response.error.unwrap().code, -32601);
+    }
 }
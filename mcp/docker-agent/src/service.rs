@@ -0,0 +1,88 @@
+//! JSON-RPC method dispatch, independent of the Docker tool set
+//!
+//! A [`Service`] answers the slice of the method namespace it knows about and
+//! returns `None` for anything else, so a [`Server`] can chain several
+//! services and fall back to `-32601 Method not found` only once none of
+//! them recognized the method. The trait itself doesn't assume a transport,
+//! but today the stdio loop (`mcp_server::run`) is the only caller that
+//! wraps its top-level JSON-RPC method routing in a `Server`; `http_transport`
+//! routes methods itself (`initialize`, `tools/list`, `tools/call`, `ping`)
+//! rather than building one. The two transports do share the one tool-call
+//! chokepoint that matters - `mcp_server::dispatch_tool_call` /
+//! `mcp_server::handle_tools_call` - via `ToolExecState`, so `tools/call`
+//! itself isn't duplicated logic, just dispatched to from two different
+//! method-routing sites.
+
+use crate::mcp_server::{JsonRpcRequest, JsonRpcResponse};
+use async_trait::async_trait;
+
+/// One slice of the JSON-RPC method namespace (e.g. "the Docker tools").
+/// Implementations should return `None` for any method they don't recognize
+/// so `Server` can try the next service instead of answering on their
+/// behalf.
+#[async_trait]
+pub trait Service: Send + Sync {
+    async fn handle(&self, request: &JsonRpcRequest) -> Option<JsonRpcResponse>;
+}
+
+/// Dispatches each request to the first service that recognizes its method,
+/// falling back to `-32601 Method not found` only when every service returns
+/// `None`.
+pub struct Server {
+    services: Vec<Box<dyn Service>>,
+}
+
+impl Server {
+    pub fn new(services: Vec<Box<dyn Service>>) -> Self {
+        Self { services }
+    }
+
+    pub async fn handle(&self, request: &JsonRpcRequest) -> JsonRpcResponse {
+        for service in &self.services {
+            if let Some(response) = service.handle(request).await {
+                return response;
+            }
+        }
+
+        JsonRpcResponse::error(request.id.clone(), -32601, "Method not found")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    struct EchoService;
+
+    #[async_trait]
+    impl Service for EchoService {
+        async fn handle(&self, request: &JsonRpcRequest) -> Option<JsonRpcResponse> {
+            (request.method == "echo")
+                .then(|| JsonRpcResponse::success(request.id.clone(), json!("echoed")))
+        }
+    }
+
+    fn request(method: &str) -> JsonRpcRequest {
+        JsonRpcRequest {
+            jsonrpc: "2.0".to_string(),
+            id: Some(json!(1)),
+            method: method.to_string(),
+            params: json!({}),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_server_dispatches_to_matching_service() {
+        let server = Server::new(vec![Box::new(EchoService)]);
+        let response = server.handle(&request("echo")).await;
+        assert_eq!(response.result, Some(json!("echoed")));
+    }
+
+    #[tokio::test]
+    async fn test_server_falls_back_to_method_not_found() {
+        let server = Server::new(vec![Box::new(EchoService)]);
+        let response = server.handle(&request("unknown")).await;
+        assert_eq!(response.error.unwrap().code, -32601);
+    }
+}
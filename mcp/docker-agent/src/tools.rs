@@ -1,6 +1,10 @@
 //! MCP tool definitions for Docker operations
 
+use std::collections::HashMap;
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 /// Available Docker tools
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,13 +26,60 @@ pub enum DockerTool {
     #[serde(rename = "docker_stop")]
     DockerStop(DockerStopArgs),
 
-    /// List all tracked containers
+    /// List containers, optionally including stopped ones and/or filtered by the daemon
     #[serde(rename = "docker_list")]
-    DockerList,
+    DockerList(DockerListArgs),
+
+    /// Sample live CPU/memory/network usage for a container
+    #[serde(rename = "docker_stats")]
+    DockerStats(DockerStatsArgs),
+
+    /// Inspect the full state of a container
+    #[serde(rename = "docker_inspect")]
+    DockerInspect(DockerInspectArgs),
+
+    /// Pull an image from a registry
+    #[serde(rename = "docker_pull")]
+    DockerPull(DockerPullArgs),
+
+    /// List local images
+    #[serde(rename = "docker_images")]
+    DockerImages(DockerImagesArgs),
+
+    /// Build an image from a Dockerfile and context directory
+    #[serde(rename = "docker_build")]
+    DockerBuild(DockerBuildArgs),
+
+    /// Wait for and collect daemon lifecycle events (container die/oom/health, etc.)
+    #[serde(rename = "docker_events")]
+    DockerEvents(DockerEventsArgs),
+
+    /// Tail-follow a container's logs, pushing lines to the session's SSE stream
+    #[serde(rename = "docker_logs_follow")]
+    DockerLogsFollow(DockerLogsFollowArgs),
+
+    /// Start tailing a container's logs, pushing each line as a `docker/logs`
+    /// JSON-RPC notification instead of a one-shot result
+    #[serde(rename = "docker_subscribe_logs")]
+    DockerSubscribeLogs(DockerSubscribeLogsArgs),
+
+    /// Start sampling a container's resource usage, pushing each sample as a
+    /// `docker/stats` JSON-RPC notification
+    #[serde(rename = "docker_subscribe_stats")]
+    DockerSubscribeStats(DockerSubscribeStatsArgs),
+
+    /// Stop a subscription started by `docker_subscribe_logs` or `docker_subscribe_stats`
+    #[serde(rename = "docker_unsubscribe")]
+    DockerUnsubscribe(DockerUnsubscribeArgs),
+
+    /// Execute a batch of tool calls across a bounded worker pool, returning
+    /// one result per sub-call in input order
+    #[serde(rename = "docker_batch")]
+    DockerBatch(DockerBatchArgs),
 }
 
 /// Arguments for starting a container
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DockerRunArgs {
     /// Docker image to run
     pub image: String,
@@ -36,18 +87,40 @@ pub struct DockerRunArgs {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub command: Option<Vec<String>>,
     /// Environment variables (e.g., ["KEY=value"])
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub env_vars: Vec<String>,
     /// Volume mounts (host_path:container_path)
-    #[serde(skip_serializing_if = "Vec::is_empty")]
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
     pub volume_mounts: Vec<String>,
     /// Container name (optional, auto-generated if not provided)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
+    /// Published ports, in `host:container[/proto]` form (e.g. "8080:80/tcp")
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub ports: Vec<String>,
+    /// Memory limit in megabytes
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_mb: Option<u64>,
+    /// Total memory+swap limit in megabytes (-1 for unlimited swap); only
+    /// meaningful alongside `memory_mb`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory_swap_mb: Option<i64>,
+    /// CPU quota in fractional cores (e.g. 1.5)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpus: Option<f64>,
+    /// Relative CPU weight versus other containers (default: 1024)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<i64>,
+    /// Restart policy: `no`, `on-failure:<max_retries>`, `always`, or `unless-stopped`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart: Option<String>,
+    /// Labels to attach to the container
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub labels: HashMap<String, String>,
 }
 
 /// Arguments for fetching logs
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DockerLogsArgs {
     /// Container ID
     pub container_id: String,
@@ -63,10 +136,13 @@ pub struct DockerLogsArgs {
     /// Include stderr (default: true)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stderr: Option<bool>,
+    /// Keep tailing new output instead of returning a one-shot snapshot (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub follow: Option<bool>,
 }
 
 /// Arguments for executing command
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DockerExecArgs {
     /// Container ID
     pub container_id: String,
@@ -75,12 +151,153 @@ pub struct DockerExecArgs {
 }
 
 /// Arguments for stopping container
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct DockerStopArgs {
     /// Container ID
     pub container_id: String,
 }
 
+/// Arguments for listing containers
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DockerListArgs {
+    /// Include stopped/exited containers, not just running ones (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+    /// Daemon-side filters (e.g. `status=[exited]`, `label=[env=prod]`)
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub filters: HashMap<String, Vec<String>>,
+}
+
+/// Arguments for sampling container resource usage
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerStatsArgs {
+    /// Container ID
+    pub container_id: String,
+    /// Keep streaming samples instead of taking a single one (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+}
+
+/// Arguments for inspecting a container
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerInspectArgs {
+    /// Container ID
+    pub container_id: String,
+}
+
+/// Arguments for pulling an image
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerPullArgs {
+    /// Image repository to pull (e.g. "nginx")
+    pub image: String,
+    /// Tag to pull (default: "latest")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Identity token for a private registry, as an opaque pre-authenticated
+    /// credential (not a username:password pair)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub registry_auth: Option<String>,
+}
+
+/// Arguments for listing local images
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerImagesArgs {
+    /// Filter images by reference substring (optional)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filter: Option<String>,
+}
+
+/// Arguments for building an image
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerBuildArgs {
+    /// Path to the build context directory
+    pub context_path: String,
+    /// Dockerfile path, relative to the context (default: "Dockerfile")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dockerfile: Option<String>,
+    /// Tag to apply to the built image (e.g. "myapp:latest")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// Build-time arguments (--build-arg KEY=VALUE)
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub build_args: HashMap<String, String>,
+    /// Disable the build cache (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nocache: Option<bool>,
+}
+
+/// Arguments for collecting daemon lifecycle events
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct DockerEventsArgs {
+    /// Only events at or after this ISO8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub since: Option<String>,
+    /// Only events at or before this ISO8601 timestamp
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub until: Option<String>,
+    /// Daemon-side filters (e.g. `type=[container]`, `event=[die,oom,health_status]`)
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub filters: HashMap<String, Vec<String>>,
+    /// Stop once this many events have been collected
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub count: Option<u64>,
+    /// Stop after this many seconds even if `count` hasn't been reached (default: 30)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+    /// Keep subscribing and push each event to the session's SSE stream
+    /// instead of collecting a bounded batch (default: false)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>,
+}
+
+/// Arguments for tail-following a container's logs over SSE
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerLogsFollowArgs {
+    /// Container ID
+    pub container_id: String,
+}
+
+/// Arguments for starting a log subscription
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerSubscribeLogsArgs {
+    /// Container ID
+    pub container_id: String,
+}
+
+/// Arguments for starting a stats subscription
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerSubscribeStatsArgs {
+    /// Container ID
+    pub container_id: String,
+}
+
+/// Arguments for cancelling a subscription
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerUnsubscribeArgs {
+    /// Subscription id returned by `docker_subscribe_logs`
+    pub subscription: u64,
+}
+
+/// One sub-call within a `docker_batch` request: the tool name and its
+/// arguments, in the same shape a top-level `tools/call` would use
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerBatchCall {
+    /// Name of the tool to invoke (e.g. "docker_logs")
+    pub name: String,
+    /// Arguments for the tool, validated the same way a top-level call's are
+    pub arguments: Value,
+}
+
+/// Arguments for a batch of tool calls
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DockerBatchArgs {
+    /// Sub-calls to execute, bounded by a worker pool sized to the host's
+    /// available parallelism. Results are returned in this same order
+    /// regardless of completion order, and one sub-call failing doesn't
+    /// stop the others from running.
+    pub calls: Vec<DockerBatchCall>,
+}
+
 /// Tool result response
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ToolResult {
@@ -107,6 +324,160 @@ pub struct DockerLogsResult {
     pub timestamp: Option<String>,
 }
 
+/// A single resource-usage sample for one container
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerStatsResult {
+    pub success: bool,
+    pub container_id: String,
+    pub cpu_percent: f64,
+    pub mem_usage_bytes: u64,
+    pub mem_limit_bytes: u64,
+    pub mem_percent: f64,
+    /// Per-interface network RX/TX byte counters
+    pub networks: HashMap<String, NetworkIoSample>,
+}
+
+/// RX/TX byte counters for one network interface
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkIoSample {
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+}
+
+/// Full container state, as reported by the daemon's inspect endpoint
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerInspectResult {
+    pub success: bool,
+    pub container_id: String,
+    pub status: String,
+    pub running: bool,
+    pub exit_code: Option<i64>,
+    pub started_at: Option<String>,
+    pub finished_at: Option<String>,
+    pub oom_killed: bool,
+    pub restart_count: i64,
+    pub image: String,
+    pub ip_address: Option<String>,
+    /// Container port (e.g. "80/tcp") to the host bindings it's published on
+    pub ports: HashMap<String, Vec<String>>,
+    pub mounts: Vec<String>,
+    pub command: Vec<String>,
+    pub env: Vec<String>,
+}
+
+/// One container entry as reported by the daemon's `/containers/json`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ContainerSummary {
+    pub id: String,
+    pub names: Vec<String>,
+    pub image: String,
+    pub state: String,
+    pub status: String,
+    /// Port mappings, in `host_ip:host_port->container_port/proto` form
+    pub ports: Vec<String>,
+}
+
+/// Result of listing containers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerListResult {
+    pub success: bool,
+    pub containers: Vec<ContainerSummary>,
+}
+
+/// Result of pulling an image
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerPullResult {
+    pub success: bool,
+    pub image: String,
+    pub digest: Option<String>,
+    /// Progress lines emitted by the daemon while pulling (one per layer event)
+    pub progress: Vec<String>,
+}
+
+/// Summary of one local image, as reported by the daemon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImageSummary {
+    pub repo_tags: Vec<String>,
+    pub id: String,
+    pub size_bytes: u64,
+    pub created: String,
+}
+
+/// Result of listing local images
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerImagesResult {
+    pub success: bool,
+    pub images: Vec<ImageSummary>,
+}
+
+/// Result of building an image
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerBuildResult {
+    pub success: bool,
+    pub image_id: Option<String>,
+    /// Build output lines, in daemon emission order
+    pub output: Vec<String>,
+}
+
+/// A single decoded daemon event
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerEventRecord {
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub action: String,
+    pub actor_id: String,
+    pub attributes: HashMap<String, String>,
+    pub time: String,
+}
+
+/// Result of a bounded `docker_events` collection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerEventsResult {
+    pub success: bool,
+    pub events: Vec<DockerEventRecord>,
+}
+
+/// Result of starting a log subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerSubscribeLogsResult {
+    pub success: bool,
+    /// Id to pass to `docker_unsubscribe`; also tagged on each `docker/logs` notification
+    pub subscription: u64,
+}
+
+/// Result of starting a stats subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerSubscribeStatsResult {
+    pub success: bool,
+    /// Id to pass to `docker_unsubscribe`; also tagged on each `docker/stats` notification
+    pub subscription: u64,
+}
+
+/// Result of cancelling a subscription
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerUnsubscribeResult {
+    pub success: bool,
+}
+
+/// Outcome of one sub-call within a `docker_batch` request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerBatchCallResult {
+    pub name: String,
+    pub success: bool,
+    pub output: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Result of a `docker_batch` call: one [`DockerBatchCallResult`] per
+/// sub-call, in the same order as the input `calls` regardless of
+/// completion order
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DockerBatchResult {
+    pub success: bool,
+    pub results: Vec<DockerBatchCallResult>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,6 +491,13 @@ mod tests {
             env_vars: vec![],
             volume_mounts: vec![],
             name: None,
+            ports: vec![],
+            memory_mb: None,
+            memory_swap_mb: None,
+            cpus: None,
+            cpu_shares: None,
+            restart: None,
+            labels: HashMap::new(),
         };
 
         let json = serde_json::to_value(&args).unwrap();
@@ -127,6 +505,13 @@ mod tests {
         // Optional fields should be skipped when empty/None
         assert!(json.get("command").is_none());
         assert!(json.get("name").is_none());
+        assert!(json.get("ports").is_none());
+        assert!(json.get("memory_mb").is_none());
+        assert!(json.get("memory_swap_mb").is_none());
+        assert!(json.get("cpus").is_none());
+        assert!(json.get("cpu_shares").is_none());
+        assert!(json.get("restart").is_none());
+        assert!(json.get("labels").is_none());
     }
 
     #[test]
@@ -137,12 +522,26 @@ mod tests {
             env_vars: vec!["PORT=8080".to_string()],
             volume_mounts: vec!["/host:/container".to_string()],
             name: Some("my-nginx".to_string()),
+            ports: vec!["8080:80/tcp".to_string()],
+            memory_mb: Some(512),
+            memory_swap_mb: Some(1024),
+            cpus: Some(1.5),
+            cpu_shares: Some(2048),
+            restart: Some("unless-stopped".to_string()),
+            labels: HashMap::from([("env".to_string(), "prod".to_string())]),
         };
 
         let json = serde_json::to_value(&args).unwrap();
         assert_eq!(json["image"], "nginx:alpine");
         assert_eq!(json["command"], json!(["nginx", "-g"]));
         assert_eq!(json["env_vars"], json!(["PORT=8080"]));
+        assert_eq!(json["ports"], json!(["8080:80/tcp"]));
+        assert_eq!(json["memory_mb"], 512);
+        assert_eq!(json["memory_swap_mb"], 1024);
+        assert_eq!(json["cpus"], 1.5);
+        assert_eq!(json["cpu_shares"], 2048);
+        assert_eq!(json["restart"], "unless-stopped");
+        assert_eq!(json["labels"]["env"], "prod");
         assert_eq!(json["volume_mounts"], json!(["/host:/container"]));
         assert_eq!(json["name"], "my-nginx");
     }
@@ -177,6 +576,7 @@ mod tests {
         assert!(args.tail_lines.is_none());
         assert!(args.stdout.is_none());
         assert!(args.stderr.is_none());
+        assert!(args.follow.is_none());
     }
 
     #[test]
@@ -186,7 +586,8 @@ mod tests {
             "since": "2024-01-01T00:00:00Z",
             "tail_lines": 100,
             "stdout": true,
-            "stderr": false
+            "stderr": false,
+            "follow": true
         });
 
         let args: DockerLogsArgs = serde_json::from_value(json).unwrap();
@@ -195,6 +596,7 @@ mod tests {
         assert_eq!(args.tail_lines, Some(100));
         assert_eq!(args.stdout, Some(true));
         assert_eq!(args.stderr, Some(false));
+        assert_eq!(args.follow, Some(true));
     }
 
     #[test]
@@ -284,6 +686,13 @@ mod tests {
             env_vars: vec![],
             volume_mounts: vec![],
             name: None,
+            ports: vec![],
+            memory_mb: None,
+            memory_swap_mb: None,
+            cpus: None,
+            cpu_shares: None,
+            restart: None,
+            labels: HashMap::new(),
         });
 
         let json = serde_json::to_value(&tool).unwrap();
@@ -312,12 +721,369 @@ mod tests {
     }
 
     #[test]
-    fn test_docker_list_variant() {
+    fn test_docker_list_variant_defaults() {
+        let json = json!({
+            "name": "docker_list",
+            "arguments": {}
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerList(args) => {
+                assert!(args.all.is_none());
+                assert!(args.filters.is_empty());
+            }
+            _ => panic!("Expected DockerList variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_list_args_with_filters() {
+        let json = json!({
+            "name": "docker_list",
+            "arguments": {
+                "all": true,
+                "filters": { "status": ["exited"] }
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerList(args) => {
+                assert_eq!(args.all, Some(true));
+                assert_eq!(args.filters.get("status"), Some(&vec!["exited".to_string()]));
+            }
+            _ => panic!("Expected DockerList variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_stats_args_deserialize() {
+        let json = json!({
+            "name": "docker_stats",
+            "arguments": {
+                "container_id": "abc123"
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerStats(args) => {
+                assert_eq!(args.container_id, "abc123");
+                assert!(args.stream.is_none());
+            }
+            _ => panic!("Expected DockerStats variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_stats_result_serialize() {
+        let result = DockerStatsResult {
+            success: true,
+            container_id: "abc123".to_string(),
+            cpu_percent: 12.5,
+            mem_usage_bytes: 1024,
+            mem_limit_bytes: 2048,
+            mem_percent: 50.0,
+            networks: HashMap::from([(
+                "eth0".to_string(),
+                NetworkIoSample {
+                    rx_bytes: 100,
+                    tx_bytes: 200,
+                },
+            )]),
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["cpu_percent"], 12.5);
+        assert_eq!(json["networks"]["eth0"]["rx_bytes"], 100);
+        assert_eq!(json["networks"]["eth0"]["tx_bytes"], 200);
+    }
+
+    #[test]
+    fn test_docker_inspect_args_deserialize() {
+        let json = json!({
+            "name": "docker_inspect",
+            "arguments": { "container_id": "abc123" }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerInspect(args) => assert_eq!(args.container_id, "abc123"),
+            _ => panic!("Expected DockerInspect variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_inspect_result_serialize() {
+        let result = DockerInspectResult {
+            success: true,
+            container_id: "abc123".to_string(),
+            status: "running".to_string(),
+            running: true,
+            exit_code: None,
+            started_at: Some("2024-01-01T00:00:00Z".to_string()),
+            finished_at: None,
+            oom_killed: false,
+            restart_count: 0,
+            image: "nginx:alpine".to_string(),
+            ip_address: Some("172.17.0.2".to_string()),
+            ports: HashMap::from([("80/tcp".to_string(), vec!["0.0.0.0:8080".to_string()])]),
+            mounts: vec!["/host:/container".to_string()],
+            command: vec!["nginx".to_string()],
+            env: vec!["PORT=8080".to_string()],
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["status"], "running");
+        assert_eq!(json["running"], true);
+        assert_eq!(json["ports"]["80/tcp"], json!(["0.0.0.0:8080"]));
+    }
+
+    #[test]
+    fn test_docker_pull_args_deserialize() {
         let json = json!({
-            "name": "docker_list"
+            "name": "docker_pull",
+            "arguments": { "image": "nginx", "tag": "alpine" }
         });
 
         let tool: DockerTool = serde_json::from_value(json).unwrap();
-        assert!(matches!(tool, DockerTool::DockerList));
+        match tool {
+            DockerTool::DockerPull(args) => {
+                assert_eq!(args.image, "nginx");
+                assert_eq!(args.tag, Some("alpine".to_string()));
+            }
+            _ => panic!("Expected DockerPull variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_images_args_defaults() {
+        let json = json!({ "name": "docker_images", "arguments": {} });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerImages(args) => assert!(args.filter.is_none()),
+            _ => panic!("Expected DockerImages variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_build_args_deserialize() {
+        let json = json!({
+            "name": "docker_build",
+            "arguments": {
+                "context_path": "./app",
+                "dockerfile": "Dockerfile.prod",
+                "tag": "myapp:latest",
+                "build_args": { "VERSION": "1.0" }
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerBuild(args) => {
+                assert_eq!(args.context_path, "./app");
+                assert_eq!(args.dockerfile, Some("Dockerfile.prod".to_string()));
+                assert_eq!(args.tag, Some("myapp:latest".to_string()));
+                assert_eq!(args.build_args.get("VERSION"), Some(&"1.0".to_string()));
+            }
+            _ => panic!("Expected DockerBuild variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_images_result_serialize() {
+        let result = DockerImagesResult {
+            success: true,
+            images: vec![ImageSummary {
+                repo_tags: vec!["nginx:alpine".to_string()],
+                id: "sha256:abc".to_string(),
+                size_bytes: 1024,
+                created: "2024-01-01T00:00:00Z".to_string(),
+            }],
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["images"][0]["repo_tags"], json!(["nginx:alpine"]));
+        assert_eq!(json["images"][0]["size_bytes"], 1024);
+    }
+
+    #[test]
+    fn test_docker_events_args_deserialize() {
+        let json = json!({
+            "name": "docker_events",
+            "arguments": {
+                "filters": { "event": ["die", "oom"] },
+                "count": 5,
+                "timeout_secs": 60
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerEvents(args) => {
+                assert_eq!(
+                    args.filters.get("event"),
+                    Some(&vec!["die".to_string(), "oom".to_string()])
+                );
+                assert_eq!(args.count, Some(5));
+                assert_eq!(args.timeout_secs, Some(60));
+            }
+            _ => panic!("Expected DockerEvents variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_events_args_stream_defaults_false() {
+        let json = json!({
+            "name": "docker_events",
+            "arguments": { "stream": true }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerEvents(args) => assert_eq!(args.stream, Some(true)),
+            _ => panic!("Expected DockerEvents variant"),
+        }
+
+        let json = json!({ "name": "docker_events", "arguments": {} });
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerEvents(args) => assert!(args.stream.is_none()),
+            _ => panic!("Expected DockerEvents variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_events_result_serialize() {
+        let result = DockerEventsResult {
+            success: true,
+            events: vec![DockerEventRecord {
+                event_type: "container".to_string(),
+                action: "die".to_string(),
+                actor_id: "abc123".to_string(),
+                attributes: HashMap::from([("exitCode".to_string(), "1".to_string())]),
+                time: "2024-01-01T00:00:00Z".to_string(),
+            }],
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["events"][0]["type"], "container");
+        assert_eq!(json["events"][0]["action"], "die");
+    }
+
+    #[test]
+    fn test_docker_logs_follow_args_deserialize() {
+        let json = json!({
+            "name": "docker_logs_follow",
+            "arguments": {
+                "container_id": "abc123"
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerLogsFollow(args) => {
+                assert_eq!(args.container_id, "abc123");
+            }
+            _ => panic!("Expected DockerLogsFollow variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_subscribe_logs_args_deserialize() {
+        let json = json!({
+            "name": "docker_subscribe_logs",
+            "arguments": {
+                "container_id": "abc123"
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerSubscribeLogs(args) => {
+                assert_eq!(args.container_id, "abc123");
+            }
+            _ => panic!("Expected DockerSubscribeLogs variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_subscribe_stats_args_deserialize() {
+        let json = json!({
+            "name": "docker_subscribe_stats",
+            "arguments": {
+                "container_id": "abc123"
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerSubscribeStats(args) => {
+                assert_eq!(args.container_id, "abc123");
+            }
+            _ => panic!("Expected DockerSubscribeStats variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_unsubscribe_args_deserialize() {
+        let json = json!({
+            "name": "docker_unsubscribe",
+            "arguments": {
+                "subscription": 7
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerUnsubscribe(args) => {
+                assert_eq!(args.subscription, 7);
+            }
+            _ => panic!("Expected DockerUnsubscribe variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_batch_args_deserialize() {
+        let json = json!({
+            "name": "docker_batch",
+            "arguments": {
+                "calls": [
+                    { "name": "docker_list", "arguments": {} },
+                    { "name": "docker_logs", "arguments": { "container_id": "abc123" } }
+                ]
+            }
+        });
+
+        let tool: DockerTool = serde_json::from_value(json).unwrap();
+        match tool {
+            DockerTool::DockerBatch(args) => {
+                assert_eq!(args.calls.len(), 2);
+                assert_eq!(args.calls[0].name, "docker_list");
+                assert_eq!(args.calls[1].name, "docker_logs");
+                assert_eq!(args.calls[1].arguments["container_id"], "abc123");
+            }
+            _ => panic!("Expected DockerBatch variant"),
+        }
+    }
+
+    #[test]
+    fn test_docker_batch_call_result_omits_error_field_on_success() {
+        // Serde shape check only; the order-preservation and mixed-outcome
+        // behavior of the real dispatcher is exercised against
+        // `handle_docker_batch` in `mcp_server`'s test module.
+        let result = DockerBatchCallResult {
+            name: "docker_list".to_string(),
+            success: true,
+            output: "[]".to_string(),
+            error: None,
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["name"], "docker_list");
+        assert_eq!(json["success"], true);
+        assert!(json.get("error").is_none());
     }
 }